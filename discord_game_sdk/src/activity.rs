@@ -16,6 +16,10 @@ use std::convert::TryInto;
 /// - Spectating
 ///     - [`with_spectate_secret`](#method.with_spectate_secret)
 ///
+/// The `with_*` methods don't validate their input,
+/// [`ActivityBuilder`](struct.ActivityBuilder.html) reports strings that are too long and other
+/// invalid fields instead.
+///
 /// > [Struct in official docs](https://discordapp.com/developers/docs/game-sdk/activities#data-models-activity-struct)
 ///
 /// ```rust
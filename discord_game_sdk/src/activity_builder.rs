@@ -0,0 +1,336 @@
+use crate::{sys, utils, Activity, ActivityError, InvalidField, UnixTimestamp};
+use std::convert::TryFrom;
+
+/// Validating builder for [`Activity`]
+///
+/// Unlike the `with_*` methods of [`Activity`], nothing is written until [`build`], which checks
+/// every field against the limits of the SDK:
+///
+/// - Strings must fit in their buffers, at most 127 bytes for most fields.
+///   With [`truncate`], longer strings are cut on a character boundary instead.
+/// - Strings must not contain NUL characters.
+/// - The party amount and capacity must fit in an `i32`.
+/// - Secrets must not be the same as the party ID.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(discord: Discord<'_, ()>, party_id: &str) -> Result<()> {
+/// let activity = ActivityBuilder::new()
+///     .state("In a group")
+///     .details("Competitive | Rank 42")
+///     .party_id(party_id)
+///     .party_amount(2)
+///     .party_capacity(5)
+///     .join_secret(format!("join-{}", party_id))
+///     .build();
+///
+/// match activity {
+///     Ok(activity) => discord.update_activity(&activity, |_, _| {}),
+///     Err(error) => {
+///         for field in error.fields() {
+///             eprintln!("invalid activity field {}: {}", field.field(), field);
+///         }
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`Activity`]: struct.Activity.html
+/// [`build`]: #method.build
+/// [`truncate`]: #method.truncate
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActivityBuilder {
+    truncate: bool,
+
+    state: String,
+    details: String,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    large_image_key: String,
+    large_image_tooltip: String,
+    small_image_key: String,
+    small_image_tooltip: String,
+    party_id: String,
+    party_amount: u32,
+    party_capacity: u32,
+    instance: bool,
+    match_secret: String,
+    join_secret: String,
+    spectate_secret: String,
+}
+
+impl ActivityBuilder {
+    /// Create a new builder with empty fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Truncate strings that are too long instead of failing validation
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// The player's current party status
+    pub fn state(&mut self, value: impl Into<String>) -> &mut Self {
+        self.state = value.into();
+        self
+    }
+
+    /// What the player is currently doing
+    pub fn details(&mut self, value: impl Into<String>) -> &mut Self {
+        self.details = value.into();
+        self
+    }
+
+    /// When the current activity has started, in UNIX time
+    pub fn start_time(&mut self, value: UnixTimestamp) -> &mut Self {
+        self.start_time = value;
+        self
+    }
+
+    /// When the current activity will end, in UNIX time
+    pub fn end_time(&mut self, value: UnixTimestamp) -> &mut Self {
+        self.end_time = value;
+        self
+    }
+
+    /// The key of an asset to display
+    pub fn large_image_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.large_image_key = value.into();
+        self
+    }
+
+    /// The tooltip displayed when hovering over the large image
+    pub fn large_image_tooltip(&mut self, value: impl Into<String>) -> &mut Self {
+        self.large_image_tooltip = value.into();
+        self
+    }
+
+    /// The key of an asset to display
+    pub fn small_image_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.small_image_key = value.into();
+        self
+    }
+
+    /// The tooltip displayed when hovering over the small image
+    pub fn small_image_tooltip(&mut self, value: impl Into<String>) -> &mut Self {
+        self.small_image_tooltip = value.into();
+        self
+    }
+
+    /// The unique identifier for the party
+    pub fn party_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.party_id = value.into();
+        self
+    }
+
+    /// The number of players currently in the party
+    pub fn party_amount(&mut self, value: u32) -> &mut Self {
+        self.party_amount = value;
+        self
+    }
+
+    /// The maximum capacity of the party
+    pub fn party_capacity(&mut self, value: u32) -> &mut Self {
+        self.party_capacity = value;
+        self
+    }
+
+    /// Whether this activity is an instanced context, like a match
+    pub fn instance(&mut self, value: bool) -> &mut Self {
+        self.instance = value;
+        self
+    }
+
+    /// The unique hash for the given match context
+    pub fn match_secret(&mut self, value: impl Into<String>) -> &mut Self {
+        self.match_secret = value.into();
+        self
+    }
+
+    /// The unique hash for chat invites and Ask to Join
+    pub fn join_secret(&mut self, value: impl Into<String>) -> &mut Self {
+        self.join_secret = value.into();
+        self
+    }
+
+    /// The unique hash for Spectate button
+    pub fn spectate_secret(&mut self, value: impl Into<String>) -> &mut Self {
+        self.spectate_secret = value.into();
+        self
+    }
+
+    /// Validates every field and builds the [`Activity`](struct.Activity.html).
+    ///
+    /// ## Errors
+    ///
+    /// [`ActivityError`](struct.ActivityError.html) listing every invalid field.
+    pub fn build(&self) -> Result<Activity, ActivityError> {
+        let mut activity = sys::DiscordActivity::default();
+        let mut errors = Vec::new();
+
+        {
+            let mut write = |field, charbuf: &mut [u8], value: &str| {
+                if let Err(error) = self.write(field, charbuf, value) {
+                    errors.push(error);
+                }
+            };
+
+            write("state", &mut activity.state, &self.state);
+            write("details", &mut activity.details, &self.details);
+            write(
+                "large_image_key",
+                &mut activity.assets.large_image,
+                &self.large_image_key,
+            );
+            write(
+                "large_image_tooltip",
+                &mut activity.assets.large_text,
+                &self.large_image_tooltip,
+            );
+            write(
+                "small_image_key",
+                &mut activity.assets.small_image,
+                &self.small_image_key,
+            );
+            write(
+                "small_image_tooltip",
+                &mut activity.assets.small_text,
+                &self.small_image_tooltip,
+            );
+            write("party_id", &mut activity.party.id, &self.party_id);
+        }
+
+        match i32::try_from(self.party_amount) {
+            Ok(value) => activity.party.size.current_size = value,
+            Err(_) => errors.push(InvalidField::OutOfRange {
+                field: "party_amount",
+                value: self.party_amount,
+            }),
+        }
+
+        match i32::try_from(self.party_capacity) {
+            Ok(value) => activity.party.size.max_size = value,
+            Err(_) => errors.push(InvalidField::OutOfRange {
+                field: "party_capacity",
+                value: self.party_capacity,
+            }),
+        }
+
+        {
+            let mut write = |field, charbuf: &mut [u8], value: &str| {
+                if !value.is_empty() && value == self.party_id {
+                    errors.push(InvalidField::SecretMatchesPartyId { field });
+                } else if let Err(error) = self.write(field, charbuf, value) {
+                    errors.push(error);
+                }
+            };
+
+            write(
+                "match_secret",
+                &mut activity.secrets.match_,
+                &self.match_secret,
+            );
+            write("join_secret", &mut activity.secrets.join, &self.join_secret);
+            write(
+                "spectate_secret",
+                &mut activity.secrets.spectate,
+                &self.spectate_secret,
+            );
+        }
+
+        activity.timestamps.start = self.start_time;
+        activity.timestamps.end = self.end_time;
+        activity.instance = self.instance;
+
+        if errors.is_empty() {
+            Ok(Activity(activity))
+        } else {
+            Err(ActivityError(errors))
+        }
+    }
+
+    // Leaves room for the NUL terminator
+    fn write(
+        &self,
+        field: &'static str,
+        charbuf: &mut [u8],
+        value: &str,
+    ) -> Result<(), InvalidField> {
+        let max = charbuf.len() - 1;
+
+        if value.contains('\0') {
+            return Err(InvalidField::InteriorNul { field });
+        }
+
+        let value = if value.len() <= max {
+            value
+        } else if self.truncate {
+            let mut end = max;
+
+            while !value.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            &value[..end]
+        } else {
+            return Err(InvalidField::TooLong {
+                field,
+                len: value.len(),
+                max,
+            });
+        };
+
+        utils::write_charbuf(charbuf, value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build() {
+        let mut builder = ActivityBuilder::new();
+        builder
+            .state("é".repeat(100))
+            .details("a\0b")
+            .party_id("party")
+            .party_capacity(3_000_000_000)
+            .join_secret("party")
+            .spectate_secret("spectate");
+
+        assert_eq!(
+            builder.build().unwrap_err().fields(),
+            &[
+                InvalidField::TooLong {
+                    field: "state",
+                    len: 200,
+                    max: 127,
+                },
+                InvalidField::InteriorNul { field: "details" },
+                InvalidField::OutOfRange {
+                    field: "party_capacity",
+                    value: 3_000_000_000,
+                },
+                InvalidField::SecretMatchesPartyId {
+                    field: "join_secret",
+                },
+            ]
+        );
+
+        let activity = builder
+            .truncate(true)
+            .details("ab")
+            .party_capacity(4)
+            .join_secret("join")
+            .build()
+            .unwrap();
+
+        assert_eq!(activity.state(), "é".repeat(63));
+        assert_eq!(activity.party_capacity(), 4);
+        assert_eq!(activity.spectate_secret(), "spectate");
+    }
+}
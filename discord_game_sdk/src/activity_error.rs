@@ -0,0 +1,81 @@
+use std::fmt;
+
+/// Validation error returned by [`ActivityBuilder::build`](struct.ActivityBuilder.html#method.build)
+///
+/// Lists every field that failed validation, in the order they appear in [`Activity`].
+///
+/// [`Activity`]: struct.Activity.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ActivityError(pub(crate) Vec<InvalidField>);
+
+impl ActivityError {
+    /// The fields that failed validation
+    pub fn fields(&self) -> &[InvalidField] {
+        &self.0
+    }
+}
+
+impl fmt::Display for ActivityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid activity: ")?;
+
+        for (n, field) in self.0.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", field)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ActivityError {}
+
+/// Reason an [`Activity`](struct.Activity.html) field failed validation
+///
+/// `field` is the name of the [`ActivityBuilder`](struct.ActivityBuilder.html) method that set it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidField {
+    /// The string doesn't fit in the SDK's buffer, and truncation was not enabled
+    TooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+
+    /// The string contains a NUL character, the SDK would cut it there
+    InteriorNul { field: &'static str },
+
+    /// The number doesn't fit in the SDK's signed integer
+    OutOfRange { field: &'static str, value: u32 },
+
+    /// The secret is the same as the party ID, the SDK refuses it with `InvalidSecret`
+    SecretMatchesPartyId { field: &'static str },
+}
+
+impl InvalidField {
+    /// The name of the field
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::TooLong { field, .. }
+            | Self::InteriorNul { field }
+            | Self::OutOfRange { field, .. }
+            | Self::SecretMatchesPartyId { field } => field,
+        }
+    }
+}
+
+impl fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong { field, len, max } => {
+                write!(f, "{} is {} bytes long, the maximum is {}", field, len, max)
+            }
+            Self::InteriorNul { field } => write!(f, "{} contains a NUL character", field),
+            Self::OutOfRange { field, value } => write!(f, "{} is out of range: {}", field, value),
+            Self::SecretMatchesPartyId { field } => write!(f, "{} matches party_id", field),
+        }
+    }
+}
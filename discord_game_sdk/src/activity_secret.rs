@@ -0,0 +1,88 @@
+use crate::{LobbyID, UnixTimestamp};
+
+/// Contents of a join or spectate secret, packed by [`SecretCodec`](struct.SecretCodec.html)
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(lobby: &Lobby, expires_at: UnixTimestamp) {
+/// let secret = ActivitySecret::new(expires_at)
+///     .with_lobby(lobby.id(), lobby.secret())
+///     .with_party_id("party-42")
+///     .with_claim("team", "blue")
+///     .clone();
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ActivitySecret {
+    pub(crate) expires_at: UnixTimestamp,
+    pub(crate) lobby: Option<(LobbyID, String)>,
+    pub(crate) party_id: String,
+    pub(crate) claims: Vec<(String, String)>,
+}
+
+impl ActivitySecret {
+    /// Create a secret valid until `expires_at`, in UNIX time
+    pub fn new(expires_at: UnixTimestamp) -> Self {
+        Self {
+            expires_at,
+            ..Default::default()
+        }
+    }
+
+    /// When the secret stops being accepted, in UNIX time
+    pub fn expires_at(&self) -> UnixTimestamp {
+        self.expires_at
+    }
+
+    /// The lobby to connect to, with its secret
+    pub fn lobby(&self) -> Option<(LobbyID, &str)> {
+        self.lobby
+            .as_ref()
+            .map(|(lobby_id, secret)| (*lobby_id, secret.as_str()))
+    }
+
+    /// The unique identifier for the party
+    pub fn party_id(&self) -> &str {
+        &self.party_id
+    }
+
+    /// The value of a custom claim
+    pub fn claim(&self, key: &str) -> Option<&str> {
+        self.claims
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Custom claims, in the order they were added
+    pub fn claims(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.claims.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// The lobby to connect to, with its secret
+    ///
+    /// Leave it out of spectate secrets unless spectators may join the lobby.
+    pub fn with_lobby(&mut self, lobby_id: LobbyID, secret: impl Into<String>) -> &mut Self {
+        self.lobby = Some((lobby_id, secret.into()));
+        self
+    }
+
+    /// The unique identifier for the party
+    pub fn with_party_id(&mut self, value: impl Into<String>) -> &mut Self {
+        self.party_id = value.into();
+        self
+    }
+
+    /// Adds a custom claim, replacing the previous value of `key`
+    pub fn with_claim(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        let key = key.into();
+        let value = value.into();
+
+        match self.claims.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.claims.push((key, value)),
+        }
+
+        self
+    }
+}
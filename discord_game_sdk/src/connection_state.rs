@@ -0,0 +1,11 @@
+/// State of the connection managed by a [`Supervisor`](struct.Supervisor.html)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionState {
+    /// An instance is available
+    Connected,
+    /// The connection was lost, the instance was dropped
+    Disconnected,
+    /// Creating an instance failed, it will be retried after a delay
+    Reconnecting,
+}
@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/discord#data-models-createflags-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CreateFlags {
     /// Requires Discord to be running to play the game
     Default,
@@ -1,10 +1,12 @@
-use crate::{sys, ClientID};
-use std::{cell::UnsafeCell, marker::PhantomData, mem::ManuallyDrop};
+use crate::{sys, ClientID, Event};
+use std::{cell::UnsafeCell, marker::PhantomData, mem::ManuallyDrop, time::Instant};
 
 /// Main interface with SDK
 ///
 /// The Discord Game SDK is not thread-safe, this struct should only be made `Send`/`Sync` with
 /// appropriate safety measures, and not as-is.
+/// [`DiscordDriver`](struct.DiscordDriver.html) provides such measures by keeping the instance on
+/// its own thread.
 ///
 /// As opposed to the general structure of the Discord Game SDK, and to help with memory and thread
 /// safety, the methods of the Manager "classes" are part of this struct.
@@ -62,7 +64,14 @@ impl<E> Drop for Discord<'_, E> {
     fn drop(&mut self) {
         unsafe {
             let core = (*self.0).core;
-            (*core).destroy.unwrap()(core);
+
+            // `core` is left null when creation failed
+            if !core.is_null() {
+                // Aborted callbacks may still use the instance
+                self.abort_retries();
+
+                (*core).destroy.unwrap()(core);
+            }
 
             drop(Box::from_raw(self.0));
         }
@@ -104,12 +113,27 @@ impl<E: std::fmt::Debug> std::fmt::Debug for Discord<'_, E> {
     }
 }
 
+// Called with `true` to attempt the operation again, `false` to abort it
+pub(crate) type DeferredRetry<'d, E> = Box<dyn 'd + FnOnce(&Discord<'d, E>, bool)>;
+
 pub(crate) struct DiscordInner<'d, E> {
     pub(crate) _invariant_lifetime: PhantomData<*mut &'d ()>,
 
     pub(crate) core: *mut sys::IDiscordCore,
     pub(crate) client_id: sys::DiscordClientId,
     pub(crate) event_handler: UnsafeCell<Option<E>>,
+    pub(crate) event_buffering: bool,
+    pub(crate) event_buffer: UnsafeCell<Vec<Event>>,
+    pub(crate) catch_panics: bool,
+    // First panic caught since the last `run_callbacks`
+    pub(crate) panic: UnsafeCell<Option<String>>,
+    pub(crate) error_context: bool,
+    // Operations waiting to be attempted again, see `Retry`
+    pub(crate) retries: UnsafeCell<Vec<(Instant, DeferredRetry<'d, E>)>>,
+
+    // Must outlive `core`, `Discord::drop` destroys `core` before dropping this
+    #[cfg(feature = "dynamic")]
+    pub(crate) library: Option<libloading::Library>,
 
     pub(crate) achievement_events: sys::IDiscordAchievementEvents,
     pub(crate) activity_events: sys::IDiscordActivityEvents,
@@ -0,0 +1,169 @@
+use crate::{
+    discord_handle::{Forwards, Message},
+    ClientID, Discord, DiscordHandle, Error, EventHandler, Result,
+};
+use std::{
+    fmt,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Owns a [`Discord`] instance on a dedicated thread
+///
+/// The thread runs [`run_callbacks`] at a fixed interval and calls made through
+/// [`DiscordHandle`]s in between.
+///
+/// The thread stops when [`run_callbacks`] fails, when [`shutdown`] is called,
+/// or when the driver is dropped. The instance is dropped on the thread, aborting pending
+/// operations.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # const DISCORD_CLIENT_ID: ClientID = 0;
+/// # fn example() -> Result<()> {
+/// let driver = DiscordDriver::<()>::new(DISCORD_CLIENT_ID)?;
+/// let handle = driver.handle();
+///
+/// std::thread::spawn(move || {
+///     let ticket = handle
+///         .call_future(|discord| discord.futures().app_ticket())
+///         .wait();
+/// });
+///
+/// // ...
+///
+/// driver.shutdown()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Discord`]: struct.Discord.html
+/// [`DiscordHandle`]: struct.DiscordHandle.html
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`shutdown`]: #method.shutdown
+pub struct DiscordDriver<E> {
+    handle: DiscordHandle<E>,
+    thread: Option<thread::JoinHandle<Result<()>>>,
+}
+
+impl<E: 'static + EventHandler> DiscordDriver<E> {
+    /// Calls [`spawn`](#method.spawn) with [`Discord::new`](struct.Discord.html#method.new)
+    /// and an interval of 16 milliseconds.
+    pub fn new(client_id: ClientID) -> Result<Self> {
+        Self::spawn(Duration::from_millis(16), move || Discord::new(client_id))
+    }
+
+    /// Starts the SDK thread, on which the instance is created with `create`.
+    ///
+    /// [`run_callbacks`](struct.Discord.html#method.run_callbacks) will be called every `interval`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors returned by `create` are passed along.
+    pub fn spawn(
+        interval: Duration,
+        create: impl 'static + Send + FnOnce() -> Result<Discord<'static, E>>,
+    ) -> Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let (created_sender, created_receiver) = mpsc::channel();
+
+        let thread = thread::Builder::new()
+            .name("discord_game_sdk".to_string())
+            .spawn(move || {
+                let discord = match create() {
+                    Ok(discord) => discord,
+                    Err(error) => {
+                        let _ = created_sender.send(Err(error));
+                        return Ok(());
+                    }
+                };
+
+                let _ = created_sender.send(Ok(()));
+                drive(discord, interval, receiver)
+            })
+            .map_err(|_| Error::Internal)?;
+
+        created_receiver.recv().map_err(|_| Error::Internal)??;
+
+        Ok(Self {
+            handle: DiscordHandle(Arc::new(Mutex::new(sender))),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl<E> DiscordDriver<E> {
+    /// Creates a new handle to the instance.
+    pub fn handle(&self) -> DiscordHandle<E> {
+        self.handle.clone()
+    }
+
+    /// Stops the SDK thread and waits for it to finish.
+    ///
+    /// ## Errors
+    ///
+    /// The error returned by [`run_callbacks`](struct.Discord.html#method.run_callbacks)
+    /// if it stopped the thread.
+    pub fn shutdown(mut self) -> Result<()> {
+        self.stop()
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        let thread = match self.thread.take() {
+            Some(thread) => thread,
+            None => return Ok(()),
+        };
+
+        let _ = self.handle.0.lock().unwrap().send(Message::Stop);
+
+        match thread.join() {
+            Ok(result) => result,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+impl<E> Drop for DiscordDriver<E> {
+    fn drop(&mut self) {
+        if let Err(error) = self.stop() {
+            log::error!("SDK thread stopped with error: {}", error);
+        }
+    }
+}
+
+impl<E> fmt::Debug for DiscordDriver<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("DiscordDriver")
+            .field("thread", &self.thread)
+            .finish()
+    }
+}
+
+fn drive<E>(
+    mut discord: Discord<'static, E>,
+    interval: Duration,
+    receiver: mpsc::Receiver<Message<E>>,
+) -> Result<()> {
+    let mut forwards: Forwards = Vec::new();
+    let mut next_run = Instant::now();
+
+    loop {
+        let now = Instant::now();
+
+        if now >= next_run {
+            discord.run_callbacks()?;
+            next_run = now + interval;
+        } else {
+            match receiver.recv_timeout(next_run - now) {
+                Ok(Message::Call(command)) => command(&mut discord, &mut forwards),
+                Ok(Message::Stop) | Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+        }
+
+        forwards = forwards
+            .into_iter()
+            .filter_map(|mut forward| if forward() { None } else { Some(forward) })
+            .collect();
+    }
+}
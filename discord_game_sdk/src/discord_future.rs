@@ -0,0 +1,159 @@
+use crate::{Error, Result};
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    thread::{self, Thread},
+};
+
+/// Result of an SDK operation, resolved during [`Discord::run_callbacks`]
+///
+/// Returned by the methods of [`Futures`]. The operation is started when the future is created,
+/// dropping the future discards its result but does not cancel the operation.
+///
+/// Like callbacks, pending futures resolve to `Err(TransactionAborted)` when the [`Discord`]
+/// instance is dropped.
+///
+/// This future is `Send` (as long as its output is), so it can be awaited from any executor,
+/// but something still has to call [`Discord::run_callbacks`] for it to make progress.
+///
+/// [`Discord`]: struct.Discord.html
+/// [`Discord::run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`Futures`]: struct.Futures.html
+pub struct DiscordFuture<T>(Arc<Mutex<Slot<T>>>);
+
+struct Slot<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+    resolved: bool,
+}
+
+// Resolves the matching `DiscordFuture`, or aborts it if dropped before doing so
+pub(crate) struct Resolver<T>(Option<Arc<Mutex<Slot<T>>>>);
+
+pub(crate) fn channel<T>() -> (Resolver<T>, DiscordFuture<T>) {
+    let slot = Arc::new(Mutex::new(Slot {
+        result: None,
+        waker: None,
+        resolved: false,
+    }));
+
+    (Resolver(Some(slot.clone())), DiscordFuture(slot))
+}
+
+pub(crate) fn ready<T>(result: Result<T>) -> DiscordFuture<T> {
+    let (resolver, future) = channel();
+    resolver.resolve(result);
+    future
+}
+
+impl<T> Resolver<T> {
+    pub(crate) fn resolve(mut self, result: Result<T>) {
+        self.send(result);
+    }
+
+    fn send(&mut self, result: Result<T>) {
+        if let Some(slot) = self.0.take() {
+            let waker = {
+                let mut slot = slot.lock().unwrap();
+                slot.result = Some(result);
+                slot.resolved = true;
+                slot.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T> Drop for Resolver<T> {
+    fn drop(&mut self) {
+        self.send(Err(Error::TransactionAborted));
+    }
+}
+
+impl<T> DiscordFuture<T> {
+    /// Whether the operation has completed, and the future will be ready when polled.
+    pub fn is_resolved(&self) -> bool {
+        self.0.lock().unwrap().resolved
+    }
+
+    /// Blocks the current thread until the operation completes.
+    ///
+    /// This must not be called from the thread running callbacks, the future would never resolve.
+    pub fn wait(mut self) -> Result<T> {
+        let waker = thread_waker(thread::current());
+        let mut context = Context::from_waker(&waker);
+
+        loop {
+            if let Poll::Ready(result) = Pin::new(&mut self).poll(&mut context) {
+                return result;
+            }
+
+            thread::park();
+        }
+    }
+
+    // Takes the result without registering a waker
+    pub(crate) fn try_take(&self) -> Option<Result<T>> {
+        self.0.lock().unwrap().result.take()
+    }
+}
+
+impl<T> Future for DiscordFuture<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.lock().unwrap();
+
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None if slot.resolved => panic!("`DiscordFuture` polled after completion"),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for DiscordFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiscordFuture")
+            .field("resolved", &self.is_resolved())
+            .finish()
+    }
+}
+
+// Wakes by unparking `thread`
+pub(crate) fn thread_waker(thread: Thread) -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop);
+
+    unsafe fn clone(ptr: *const ()) -> RawWaker {
+        let thread = Arc::from_raw(ptr as *const Thread);
+        let cloned = thread.clone();
+        std::mem::forget(thread);
+        RawWaker::new(Arc::into_raw(cloned) as *const (), &VTABLE)
+    }
+
+    unsafe fn wake(ptr: *const ()) {
+        Arc::from_raw(ptr as *const Thread).unpark();
+    }
+
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        (*(ptr as *const Thread)).unpark();
+    }
+
+    unsafe fn drop(ptr: *const ()) {
+        std::mem::drop(Arc::from_raw(ptr as *const Thread));
+    }
+
+    let ptr = Arc::into_raw(Arc::new(thread)) as *const ();
+
+    // SAFETY: the vtable functions uphold the contract of `RawWaker`
+    unsafe { Waker::from_raw(RawWaker::new(ptr, &VTABLE)) }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    discord_future::{self, DiscordFuture},
+    Discord, Error,
+};
+use std::{
+    fmt,
+    sync::{mpsc, Arc, Mutex},
+};
+
+pub(crate) type Command<E> = Box<dyn FnOnce(&mut Discord<'static, E>, &mut Forwards) + Send>;
+
+// Futures produced on the SDK thread, checked after every command and `run_callbacks`
+pub(crate) type Forwards = Vec<Box<dyn FnMut() -> bool>>;
+
+pub(crate) enum Message<E> {
+    Call(Command<E>),
+    Stop,
+}
+
+/// Thread-safe handle to a [`Discord`] instance owned by a [`DiscordDriver`]
+///
+/// Handles are cheap to clone and can be sent to and shared between threads.
+/// Calls are queued and run in order on the SDK thread, between calls to
+/// [`run_callbacks`]. Their results come back as [`DiscordFuture`]s, which can be awaited from
+/// any executor or blocked on with [`DiscordFuture::wait`].
+///
+/// Once the driver has stopped, calls resolve to `Err(NotRunning)`.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(handle: DiscordHandle<()>) -> Result<()> {
+/// let worker = std::thread::spawn(move || -> Result<()> {
+///     let lobby = handle
+///         .call_future(|discord| {
+///             discord
+///                 .futures()
+///                 .create_lobby(LobbyTransaction::new().capacity(4))
+///         })
+///         .wait()?;
+///
+///     let map = handle
+///         .call(move |discord| discord.lobby_metadata(lobby.id(), "map"))
+///         .wait()??;
+///
+///     Ok(())
+/// });
+/// # Ok(()) }
+/// ```
+///
+/// [`Discord`]: struct.Discord.html
+/// [`DiscordDriver`]: struct.DiscordDriver.html
+/// [`DiscordFuture`]: struct.DiscordFuture.html
+/// [`DiscordFuture::wait`]: struct.DiscordFuture.html#method.wait
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+pub struct DiscordHandle<E>(pub(crate) Arc<Mutex<mpsc::Sender<Message<E>>>>);
+
+impl<E> DiscordHandle<E> {
+    /// Runs `function` on the SDK thread and resolves with what it returns.
+    pub fn call<T: 'static + Send>(
+        &self,
+        function: impl 'static + Send + FnOnce(&mut Discord<'static, E>) -> T,
+    ) -> DiscordFuture<T> {
+        let (resolver, future) = discord_future::channel();
+
+        self.send(
+            Box::new(move |discord, _| resolver.resolve(Ok(function(discord)))),
+            future,
+        )
+    }
+
+    /// Runs `function` on the SDK thread and resolves with the future it returns.
+    ///
+    /// Meant to be used with [`Discord::futures`](struct.Discord.html#method.futures).
+    pub fn call_future<T: 'static + Send>(
+        &self,
+        function: impl 'static + Send + FnOnce(&mut Discord<'static, E>) -> DiscordFuture<T>,
+    ) -> DiscordFuture<T> {
+        let (resolver, future) = discord_future::channel();
+
+        self.send(
+            Box::new(move |discord, forwards| {
+                let inner = function(discord);
+                let mut resolver = Some(resolver);
+
+                forwards.push(Box::new(move || match inner.try_take() {
+                    Some(result) => {
+                        resolver.take().unwrap().resolve(result);
+                        true
+                    }
+                    None => false,
+                }));
+            }),
+            future,
+        )
+    }
+
+    fn send<T>(&self, command: Command<E>, future: DiscordFuture<T>) -> DiscordFuture<T> {
+        match self.0.lock().unwrap().send(Message::Call(command)) {
+            Ok(()) => future,
+            Err(_) => discord_future::ready(Err(Error::NotRunning)),
+        }
+    }
+}
+
+impl<E> Clone for DiscordHandle<E> {
+    fn clone(&self) -> Self {
+        DiscordHandle(self.0.clone())
+    }
+}
+
+impl<E> fmt::Debug for DiscordHandle<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("DiscordHandle").finish()
+    }
+}
@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/store#data-models-entitlementtype-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EntitlementKind {
     /// Entitlement was gifted by a developer
     DeveloperGift,
@@ -37,3 +38,18 @@ impl From<sys::EDiscordEntitlementType> for EntitlementKind {
         }
     }
 }
+
+impl Into<sys::EDiscordEntitlementType> for EntitlementKind {
+    fn into(self) -> sys::EDiscordEntitlementType {
+        match self {
+            Self::DeveloperGift => sys::DiscordEntitlementType_DeveloperGift,
+            Self::FreePurchase => sys::DiscordEntitlementType_FreePurchase,
+            Self::PremiumPurchase => sys::DiscordEntitlementType_PremiumPurchase,
+            Self::PremiumSubscription => sys::DiscordEntitlementType_PremiumSubscription,
+            Self::Purchase => sys::DiscordEntitlementType_Purchase,
+            Self::TestModePurchase => sys::DiscordEntitlementType_TestModePurchase,
+            Self::UserGift => sys::DiscordEntitlementType_UserGift,
+            Self::Undefined(n) => n,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::sys;
+use crate::{sys, ErrorContext, SdkVersions};
 use std::fmt;
 
 /// Alias for a `Result` with the error type [`discord_game_sdk::Error`]
@@ -8,14 +8,24 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 /// Discord Error
 ///
+/// Not `Copy`, as [`Context`](#variant.Context) carries the arguments of the failed call.
+///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/discord#data-models-result-enum)
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// Discord isn't working
     ServiceUnavailable,
 
     /// The SDK version is outdated
-    InvalidVersion,
+    ///
+    /// `expected` are the [`sdk_versions`](fn.sdk_versions.html) this crate was built against,
+    /// `actual` the versions supported by the SDK library, `None` when the library does not
+    /// report them, which the Discord Game SDK currently never does.
+    InvalidVersion {
+        expected: SdkVersions,
+        actual: Option<SdkVersions>,
+    },
 
     /// An internal erorr on transactional operations
     LockFailed,
@@ -140,6 +150,18 @@ pub enum Error {
     /// Transaction aborted
     TransactionAborted,
 
+    /// The SDK library could not be loaded, only returned with the `dynamic` feature
+    LibraryNotFound,
+
+    /// A callback or [`EventHandler`](trait.EventHandler.html) method panicked, with the panic
+    /// message, only returned after enabling
+    /// [`set_catch_panics`](struct.Discord.html#method.set_catch_panics)
+    CallbackPanicked(String),
+
+    /// The SDK call that returned the error, only returned after enabling
+    /// [`set_error_context`](struct.Discord.html#method.set_error_context)
+    Context(Box<ErrorContext>),
+
     /// Safety net for missing definitions
     Undefined(sys::EDiscordResult),
 }
@@ -150,7 +172,14 @@ impl fmt::Display for Error {
 
         let message = match self {
             ServiceUnavailable => "service unavailable",
-            InvalidVersion => "invalid version",
+            InvalidVersion { expected, actual } => {
+                write!(f, "invalid version: expected {}, ", expected)?;
+
+                return match actual {
+                    Some(actual) => write!(f, "the library supports {}", actual),
+                    None => write!(f, "the library does not report its versions"),
+                };
+            }
             LockFailed => "lock failed",
             Internal => "internal error",
             InvalidPayload => "invalid payload",
@@ -192,6 +221,9 @@ impl fmt::Display for Error {
             InvalidGiftCode => "invalid gift code",
             Purchase => "purchase error",
             TransactionAborted => "transaction aborted",
+            LibraryNotFound => "library not found",
+            CallbackPanicked(message) => return write!(f, "callback panicked: {}", message),
+            Context(context) => return write!(f, "{}", context),
             Undefined(n) => return write!(f, "undefined error {}", n),
         };
 
@@ -199,4 +231,32 @@ impl fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl Error {
+    /// The error returned by the SDK, without its [`ErrorContext`](struct.ErrorContext.html)
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # fn example(discord: Discord<'_, ()>, user_id: UserID) -> Result<()> {
+    /// match discord.relationship_with(user_id) {
+    ///     Ok(relationship) => println!("{:?}", relationship.kind()),
+    ///     Err(error) if error.kind() == &Error::NotFound => println!("not a friend"),
+    ///     Err(error) => return Err(error),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn kind(&self) -> &Error {
+        match self {
+            Error::Context(context) => context.error.kind(),
+            error => error,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context(context) => Some(&context.error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{Discord, Error, Result};
+use std::{fmt, time::SystemTime};
+
+/// The SDK call that returned an error
+///
+/// Only attached to errors after enabling
+/// [`set_error_context`](struct.Discord.html#method.set_error_context), as
+/// [`Error::Context`](enum.Error.html#variant.Context).
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>, lobby_id: LobbyID) -> Result<()> {
+/// discord.set_error_context(true);
+///
+/// if let Err(Error::Context(context)) = discord.lobby_metadata(lobby_id, "map") {
+///     // "lobbies::lobby_metadata(lobby_id: 42, key: map) failed"
+///     eprintln!("{}: {}", context, context.error());
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorContext {
+    pub(crate) call: Call,
+    pub(crate) error: Error,
+}
+
+// Owned, so that it can be deserialized
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Call {
+    manager: String,
+    method: String,
+    arguments: Vec<(String, String)>,
+    issued_at: Option<SystemTime>,
+}
+
+impl Call {
+    pub(crate) fn new(
+        manager: &'static str,
+        method: &'static str,
+        arguments: Vec<(&'static str, String)>,
+    ) -> Self {
+        Self {
+            manager: manager.to_string(),
+            method: method.to_string(),
+            arguments: arguments
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+            issued_at: None,
+        }
+    }
+}
+
+impl ErrorContext {
+    /// The error returned by the SDK
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// The name of the manager, such as `"lobbies"` or `"storage"`
+    pub fn manager(&self) -> &str {
+        &self.call.manager
+    }
+
+    /// The name of the method of [`Discord`](struct.Discord.html)
+    pub fn method(&self) -> &str {
+        &self.call.method
+    }
+
+    /// The names and values of the relevant arguments, such as IDs, keys or filenames
+    pub fn arguments(&self) -> &[(String, String)] {
+        &self.call.arguments
+    }
+
+    /// When the request was issued, only for methods taking a callback
+    pub fn issued_at(&self) -> Option<SystemTime> {
+        self.call.issued_at
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}(", self.call.manager, self.call.method)?;
+
+        for (n, (name, value)) in self.call.arguments.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}: {}", name, value)?;
+        }
+
+        write!(f, ") failed")
+    }
+}
+
+impl std::error::Error for ErrorContext {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+pub(crate) trait WithContext {
+    fn context(self, call: &Option<Call>) -> Self;
+}
+
+impl<T> WithContext for Result<T> {
+    fn context(self, call: &Option<Call>) -> Self {
+        match (self, call) {
+            (Err(error), Some(call)) => Err(Error::Context(Box::new(ErrorContext {
+                call: call.clone(),
+                error,
+            }))),
+            (result, _) => result,
+        }
+    }
+}
+
+impl<E> Discord<'_, E> {
+    /// Enables or disables attaching an [`ErrorContext`] to the errors returned by methods.
+    ///
+    /// Disabled by default, as errors are then wrapped in [`Error::Context`]:
+    /// use [`Error::kind`] to match on the error returned by the SDK.
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+    /// discord.set_error_context(true);
+    ///
+    /// match discord.file_stat("profile_1.save") {
+    ///     Ok(stat) => println!("{} bytes", stat.size()),
+    ///     Err(error) if error.kind() == &Error::NotFound => println!("no save yet"),
+    ///     Err(error) => return Err(error),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`ErrorContext`]: struct.ErrorContext.html
+    /// [`Error::Context`]: enum.Error.html#variant.Context
+    /// [`Error::kind`]: enum.Error.html#method.kind
+    pub fn set_error_context(&mut self, enabled: bool) {
+        self.inner_mut().error_context = enabled;
+    }
+
+    // Only evaluates `arguments` when enabled
+    pub(crate) fn call_context(
+        &self,
+        manager: &'static str,
+        method: &'static str,
+        arguments: impl FnOnce() -> Vec<(&'static str, String)>,
+    ) -> Option<Call> {
+        if !self.inner().error_context {
+            return None;
+        }
+
+        Some(Call::new(manager, method, arguments()))
+    }
+
+    pub(crate) fn async_call_context(
+        &self,
+        manager: &'static str,
+        method: &'static str,
+        arguments: impl FnOnce() -> Vec<(&'static str, String)>,
+    ) -> Option<Call> {
+        self.call_context(manager, method, arguments)
+            .map(|call| Call {
+                issued_at: Some(SystemTime::now()),
+                ..call
+            })
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    Action, Activity, Entitlement, LobbyID, NetworkChannelID, NetworkPeerID, Relationship, User,
+    UserAchievement, UserID,
+};
+
+/// Owned SDK event, buffered during [`run_callbacks`]
+///
+/// Each variant matches a method of [`EventHandler`], see its documentation for details.
+/// Buffering must be enabled with [`set_event_buffering`], events are then retrieved with
+/// [`poll_events`] or [`drain_events`].
+///
+/// [`EventHandler`]: trait.EventHandler.html
+/// [`drain_events`]: struct.Discord.html#method.drain_events
+/// [`poll_events`]: struct.Discord.html#method.poll_events
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`set_event_buffering`]: struct.Discord.html#method.set_event_buffering
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// [`on_user_achievement_update`](trait.EventHandler.html#method.on_user_achievement_update)
+    UserAchievementUpdate { user_achievement: UserAchievement },
+
+    /// [`on_activity_join`](trait.EventHandler.html#method.on_activity_join)
+    ActivityJoin { secret: String },
+
+    /// [`on_activity_spectate`](trait.EventHandler.html#method.on_activity_spectate)
+    ActivitySpectate { secret: String },
+
+    /// [`on_activity_join_request`](trait.EventHandler.html#method.on_activity_join_request)
+    ActivityJoinRequest { user: User },
+
+    /// [`on_activity_invite`](trait.EventHandler.html#method.on_activity_invite)
+    ActivityInvite {
+        kind: Action,
+        user: User,
+        activity: Activity,
+    },
+
+    /// [`on_lobby_update`](trait.EventHandler.html#method.on_lobby_update)
+    LobbyUpdate { lobby_id: LobbyID },
+
+    /// [`on_lobby_delete`](trait.EventHandler.html#method.on_lobby_delete)
+    LobbyDelete { lobby_id: LobbyID, reason: u32 },
+
+    /// [`on_member_connect`](trait.EventHandler.html#method.on_member_connect)
+    MemberConnect {
+        lobby_id: LobbyID,
+        member_id: UserID,
+    },
+
+    /// [`on_member_update`](trait.EventHandler.html#method.on_member_update)
+    MemberUpdate {
+        lobby_id: LobbyID,
+        member_id: UserID,
+    },
+
+    /// [`on_member_disconnect`](trait.EventHandler.html#method.on_member_disconnect)
+    MemberDisconnect {
+        lobby_id: LobbyID,
+        member_id: UserID,
+    },
+
+    /// [`on_lobby_message`](trait.EventHandler.html#method.on_lobby_message)
+    LobbyMessage {
+        lobby_id: LobbyID,
+        member_id: UserID,
+        data: Vec<u8>,
+    },
+
+    /// [`on_speaking`](trait.EventHandler.html#method.on_speaking)
+    Speaking {
+        lobby_id: LobbyID,
+        member_id: UserID,
+        speaking: bool,
+    },
+
+    /// [`on_lobby_network_message`](trait.EventHandler.html#method.on_lobby_network_message)
+    LobbyNetworkMessage {
+        lobby_id: LobbyID,
+        member_id: UserID,
+        channel_id: NetworkChannelID,
+        data: Vec<u8>,
+    },
+
+    /// [`on_network_message`](trait.EventHandler.html#method.on_network_message)
+    NetworkMessage {
+        peer_id: NetworkPeerID,
+        channel_id: NetworkChannelID,
+        data: Vec<u8>,
+    },
+
+    /// [`on_network_route_update`](trait.EventHandler.html#method.on_network_route_update)
+    NetworkRouteUpdate { route: String },
+
+    /// [`on_overlay_toggle`](trait.EventHandler.html#method.on_overlay_toggle)
+    OverlayToggle { closed: bool },
+
+    /// [`on_relationships_refresh`](trait.EventHandler.html#method.on_relationships_refresh)
+    RelationshipsRefresh,
+
+    /// [`on_relationship_update`](trait.EventHandler.html#method.on_relationship_update)
+    RelationshipUpdate { relationship: Relationship },
+
+    /// [`on_entitlement_create`](trait.EventHandler.html#method.on_entitlement_create)
+    EntitlementCreate { entitlement: Entitlement },
+
+    /// [`on_entitlement_delete`](trait.EventHandler.html#method.on_entitlement_delete)
+    EntitlementDelete { entitlement: Entitlement },
+
+    /// [`on_current_user_update`](trait.EventHandler.html#method.on_current_user_update)
+    CurrentUserUpdate,
+
+    /// [`on_voice_settings_update`](trait.EventHandler.html#method.on_voice_settings_update)
+    VoiceSettingsUpdate,
+}
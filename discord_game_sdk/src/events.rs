@@ -1,12 +1,10 @@
 use crate::{
     discord::{Discord, DiscordInner},
-    sys, utils, Activity, Entitlement, EventHandler, Relationship, User, UserAchievement,
+    sys, utils, Activity, Entitlement, Event, EventHandler, Relationship, User, UserAchievement,
 };
 use std::{ffi::c_void, mem::ManuallyDrop};
 
 fn with_event_handler<E>(inner: *mut c_void, callback: impl FnOnce(&mut E, &Discord<'_, E>)) {
-    let _guard = utils::prevent_unwind();
-
     debug_assert!(!inner.is_null());
 
     let discord = &ManuallyDrop::new(Discord(inner as *mut DiscordInner<'_, E>));
@@ -17,8 +15,9 @@ fn with_event_handler<E>(inner: *mut c_void, callback: impl FnOnce(&mut E, &Disc
     // - `EventHandler` can mutate itself during method but not `&Discord`
     let mut event_handler = unsafe { (*discord.inner().event_handler.get()).take() };
 
+    // The event handler is put back even if it panicked
     if let Some(event_handler) = event_handler.as_mut() {
-        callback(event_handler, discord);
+        utils::guard_callback(discord.0, || callback(event_handler, discord));
     }
 
     // SAFETY: See previous
@@ -27,6 +26,23 @@ fn with_event_handler<E>(inner: *mut c_void, callback: impl FnOnce(&mut E, &Disc
     }
 }
 
+fn buffer_event<E>(inner: *mut c_void, event: impl FnOnce() -> Event) {
+    debug_assert!(!inner.is_null());
+
+    let inner = inner as *mut DiscordInner<'_, E>;
+
+    utils::guard_callback(inner, || {
+        // SAFETY: Mutating through an immutable reference
+        // - `event_buffer` is an `UnsafeCell`, inner mutation is legal
+        // - It is only otherwise accessed through `&mut Discord`, which can't exist during callbacks
+        unsafe {
+            if (*inner).event_buffering {
+                (*(*inner).event_buffer.get()).push(event());
+            }
+        }
+    })
+}
+
 pub(crate) fn achievement<E: EventHandler>() -> sys::IDiscordAchievementEvents {
     sys::IDiscordAchievementEvents {
         on_user_achievement_update: {
@@ -34,6 +50,11 @@ pub(crate) fn achievement<E: EventHandler>() -> sys::IDiscordAchievementEvents {
                 inner: *mut c_void,
                 user_achievement: *mut sys::DiscordUserAchievement,
             ) {
+                buffer_event::<E>(inner, || Event::UserAchievementUpdate {
+                    user_achievement: unsafe { &*(user_achievement as *const UserAchievement) }
+                        .clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_user_achievement_update(discord, unsafe {
                         &*(user_achievement as *const UserAchievement)
@@ -50,6 +71,10 @@ pub(crate) fn activity<E: EventHandler>() -> sys::IDiscordActivityEvents {
     sys::IDiscordActivityEvents {
         on_activity_join: {
             extern "C" fn on_activity_join<E: EventHandler>(inner: *mut c_void, secret: *const u8) {
+                buffer_event::<E>(inner, || Event::ActivityJoin {
+                    secret: unsafe { utils::charptr_to_str(secret) }.to_string(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_activity_join(discord, unsafe { utils::charptr_to_str(secret) })
                 })
@@ -63,6 +88,10 @@ pub(crate) fn activity<E: EventHandler>() -> sys::IDiscordActivityEvents {
                 inner: *mut c_void,
                 secret: *const u8,
             ) {
+                buffer_event::<E>(inner, || Event::ActivitySpectate {
+                    secret: unsafe { utils::charptr_to_str(secret) }.to_string(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_activity_spectate(discord, unsafe { utils::charptr_to_str(secret) })
                 })
@@ -76,6 +105,10 @@ pub(crate) fn activity<E: EventHandler>() -> sys::IDiscordActivityEvents {
                 inner: *mut c_void,
                 user: *mut sys::DiscordUser,
             ) {
+                buffer_event::<E>(inner, || Event::ActivityJoinRequest {
+                    user: unsafe { &*(user as *const User) }.clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_activity_join_request(discord, unsafe { &*(user as *const User) })
                 })
@@ -91,6 +124,12 @@ pub(crate) fn activity<E: EventHandler>() -> sys::IDiscordActivityEvents {
                 user: *mut sys::DiscordUser,
                 activity: *mut sys::DiscordActivity,
             ) {
+                buffer_event::<E>(inner, || Event::ActivityInvite {
+                    kind: kind.into(),
+                    user: unsafe { &*(user as *const User) }.clone(),
+                    activity: unsafe { &*(activity as *const Activity) }.clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_activity_invite(
                         discord,
@@ -113,6 +152,8 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 inner: *mut c_void,
                 lobby_id: sys::DiscordLobbyId,
             ) {
+                buffer_event::<E>(inner, || Event::LobbyUpdate { lobby_id });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_lobby_update(discord, lobby_id)
                 })
@@ -127,6 +168,8 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 lobby_id: sys::DiscordLobbyId,
                 reason: u32,
             ) {
+                buffer_event::<E>(inner, || Event::LobbyDelete { lobby_id, reason });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_lobby_delete(discord, lobby_id, reason)
                 })
@@ -141,6 +184,11 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 lobby_id: sys::DiscordLobbyId,
                 member_id: sys::DiscordUserId,
             ) {
+                buffer_event::<E>(inner, || Event::MemberConnect {
+                    lobby_id,
+                    member_id,
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_member_connect(discord, lobby_id, member_id)
                 })
@@ -155,6 +203,11 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 lobby_id: sys::DiscordLobbyId,
                 member_id: sys::DiscordUserId,
             ) {
+                buffer_event::<E>(inner, || Event::MemberUpdate {
+                    lobby_id,
+                    member_id,
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_member_update(discord, lobby_id, member_id)
                 })
@@ -169,6 +222,11 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 lobby_id: sys::DiscordLobbyId,
                 member_id: sys::DiscordUserId,
             ) {
+                buffer_event::<E>(inner, || Event::MemberDisconnect {
+                    lobby_id,
+                    member_id,
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_member_disconnect(discord, lobby_id, member_id)
                 })
@@ -185,6 +243,12 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 data: *mut u8,
                 data_len: u32,
             ) {
+                buffer_event::<E>(inner, || Event::LobbyMessage {
+                    lobby_id,
+                    member_id,
+                    data: unsafe { std::slice::from_raw_parts(data, data_len as usize) }.to_vec(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_lobby_message(discord, lobby_id, member_id, unsafe {
                         std::slice::from_raw_parts(data, data_len as usize)
@@ -202,6 +266,12 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 member_id: sys::DiscordUserId,
                 speaking: bool,
             ) {
+                buffer_event::<E>(inner, || Event::Speaking {
+                    lobby_id,
+                    member_id,
+                    speaking,
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_speaking(discord, lobby_id, member_id, speaking)
                 })
@@ -219,6 +289,13 @@ pub(crate) fn lobby<E: EventHandler>() -> sys::IDiscordLobbyEvents {
                 data: *mut u8,
                 data_len: u32,
             ) {
+                buffer_event::<E>(inner, || Event::LobbyNetworkMessage {
+                    lobby_id,
+                    member_id,
+                    channel_id,
+                    data: unsafe { std::slice::from_raw_parts(data, data_len as usize) }.to_vec(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_lobby_network_message(discord, lobby_id, member_id, channel_id, unsafe {
                         std::slice::from_raw_parts(data, data_len as usize)
@@ -241,6 +318,12 @@ pub(crate) fn network<E: EventHandler>() -> sys::IDiscordNetworkEvents {
                 data: *mut u8,
                 data_len: u32,
             ) {
+                buffer_event::<E>(inner, || Event::NetworkMessage {
+                    peer_id,
+                    channel_id,
+                    data: unsafe { std::slice::from_raw_parts(data, data_len as usize) }.to_vec(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_network_message(discord, peer_id, channel_id, unsafe {
                         std::slice::from_raw_parts(data, data_len as usize)
@@ -253,6 +336,10 @@ pub(crate) fn network<E: EventHandler>() -> sys::IDiscordNetworkEvents {
 
         on_route_update: {
             extern "C" fn on_route_update<E: EventHandler>(inner: *mut c_void, route: *const u8) {
+                buffer_event::<E>(inner, || Event::NetworkRouteUpdate {
+                    route: unsafe { utils::charptr_to_str(route) }.to_string(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_network_route_update(discord, unsafe { utils::charptr_to_str(route) })
                 })
@@ -267,6 +354,8 @@ pub(crate) fn overlay<E: EventHandler>() -> sys::IDiscordOverlayEvents {
     sys::IDiscordOverlayEvents {
         on_toggle: {
             extern "C" fn on_toggle<E: EventHandler>(inner: *mut c_void, locked: bool) {
+                buffer_event::<E>(inner, || Event::OverlayToggle { closed: !locked });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_overlay_toggle(discord, !locked)
                 })
@@ -281,6 +370,8 @@ pub(crate) fn relationship<E: EventHandler>() -> sys::IDiscordRelationshipEvents
     sys::IDiscordRelationshipEvents {
         on_refresh: {
             extern "C" fn on_refresh<E: EventHandler>(inner: *mut c_void) {
+                buffer_event::<E>(inner, || Event::RelationshipsRefresh);
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_relationships_refresh(discord)
                 })
@@ -294,6 +385,10 @@ pub(crate) fn relationship<E: EventHandler>() -> sys::IDiscordRelationshipEvents
                 inner: *mut c_void,
                 relationship: *mut sys::DiscordRelationship,
             ) {
+                buffer_event::<E>(inner, || Event::RelationshipUpdate {
+                    relationship: unsafe { &*(relationship as *const Relationship) }.clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_relationship_update(discord, unsafe {
                         &*(relationship as *const Relationship)
@@ -313,6 +408,10 @@ pub(crate) fn store<E: EventHandler>() -> sys::IDiscordStoreEvents {
                 inner: *mut c_void,
                 entitlement: *mut sys::DiscordEntitlement,
             ) {
+                buffer_event::<E>(inner, || Event::EntitlementCreate {
+                    entitlement: unsafe { &*(entitlement as *const Entitlement) }.clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_entitlement_create(discord, unsafe {
                         &*(entitlement as *const Entitlement)
@@ -328,6 +427,10 @@ pub(crate) fn store<E: EventHandler>() -> sys::IDiscordStoreEvents {
                 inner: *mut c_void,
                 entitlement: *mut sys::DiscordEntitlement,
             ) {
+                buffer_event::<E>(inner, || Event::EntitlementDelete {
+                    entitlement: unsafe { &*(entitlement as *const Entitlement) }.clone(),
+                });
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_entitlement_delete(discord, unsafe {
                         &*(entitlement as *const Entitlement)
@@ -344,6 +447,8 @@ pub(crate) fn user<E: EventHandler>() -> sys::IDiscordUserEvents {
     sys::IDiscordUserEvents {
         on_current_user_update: {
             extern "C" fn on_current_user_update<E: EventHandler>(inner: *mut c_void) {
+                buffer_event::<E>(inner, || Event::CurrentUserUpdate);
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_current_user_update(discord)
                 })
@@ -358,6 +463,8 @@ pub(crate) fn voice<E: EventHandler>() -> sys::IDiscordVoiceEvents {
     sys::IDiscordVoiceEvents {
         on_settings_update: {
             extern "C" fn on_settings_update<E: EventHandler>(inner: *mut c_void) {
+                buffer_event::<E>(inner, || Event::VoiceSettingsUpdate);
+
                 with_event_handler(inner, |eh: &mut E, discord| {
                     eh.on_voice_settings_update(discord)
                 })
@@ -0,0 +1,406 @@
+use crate::{
+    discord_future::{self, DiscordFuture},
+    Action, Activity, Discord, FetchKind, ImageHandle, InputMode, Lobby, LobbyID,
+    LobbyMemberTransaction, LobbyTransaction, OAuth2Token, RequestReply, SearchQuery, Snowflake,
+    User, UserID,
+};
+use std::borrow::Cow;
+
+/// Future-based variants of the methods taking a `callback`
+///
+/// Obtained with [`Discord::futures`]. Every method mirrors the method of the same name on
+/// [`Discord`], except it returns a [`DiscordFuture`] instead of taking a callback.
+/// Borrowed results (`&Lobby`, `&User`, `&[u8]`, ...) are cloned into owned values.
+///
+/// The futures are resolved by [`Discord::run_callbacks`], use [`Discord::run_until`] to drive
+/// one to completion from a plain loop.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>, lobby_id: LobbyID) -> Result<()> {
+/// let lobby = discord.run_until(|futures| futures.connect_lobby(lobby_id, "secret"))??;
+/// let owner = discord.run_until(|futures| futures.user(lobby.owner_id()))??;
+///
+/// let activity = Activity::empty()
+///     .with_state(&format!("Playing with {}", owner.username()))
+///     .with_party_id(&lobby_id.to_string())
+///     .clone();
+///
+/// discord.run_until(|futures| futures.update_activity(&activity))??;
+/// # Ok(()) }
+/// ```
+///
+/// With Rust 1.39 and up, steps can be chained in an `async` block instead:
+///
+/// ```rust,ignore
+/// let owner = discord.run_until(|futures| async move {
+///     let lobby = futures.connect_lobby(lobby_id, "secret").await?;
+///     futures.user(lobby.owner_id()).await
+/// })??;
+/// ```
+///
+/// ### Async runtimes
+///
+/// [`DiscordFuture`] does not depend on any runtime. Keep [`Discord`] on a single thread, call
+/// [`Discord::run_callbacks`] periodically, and await the futures wherever they're needed,
+/// for example with a `tokio::task::LocalSet` or a `smol::LocalExecutor`:
+///
+/// ```rust,ignore
+/// let discord = Rc::new(RefCell::new(Discord::new(DISCORD_CLIENT_ID)?));
+///
+/// local.spawn_local({
+///     let discord = discord.clone();
+///     async move {
+///         loop {
+///             discord.borrow_mut().run_callbacks().unwrap();
+///             tokio::time::sleep(Duration::from_millis(16)).await;
+///         }
+///     }
+/// });
+///
+/// // don't hold the borrow across `.await`
+/// let user = discord.borrow().futures().user(user_id);
+/// let user = user.await?;
+/// ```
+///
+/// [`Discord`]: struct.Discord.html
+/// [`Discord::futures`]: struct.Discord.html#method.futures
+/// [`Discord::run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`Discord::run_until`]: struct.Discord.html#method.run_until
+/// [`DiscordFuture`]: struct.DiscordFuture.html
+pub struct Futures<'a, 'd, E>(pub(crate) &'a Discord<'d, E>);
+
+impl<E> Clone for Futures<'_, '_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Futures<'_, '_, E> {}
+
+impl<E: std::fmt::Debug> std::fmt::Debug for Futures<'_, '_, E> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_tuple("Futures").field(self.0).finish()
+    }
+}
+
+impl<'d, E> Futures<'_, 'd, E> {
+    /// [`Discord::set_user_achievement`](struct.Discord.html#method.set_user_achievement)
+    pub fn set_user_achievement(
+        self,
+        achievement_id: Snowflake,
+        percent_complete: u8,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .set_user_achievement(achievement_id, percent_complete, |_, res| {
+                resolver.resolve(res)
+            });
+        future
+    }
+
+    /// [`Discord::fetch_user_achievements`](struct.Discord.html#method.fetch_user_achievements)
+    pub fn fetch_user_achievements(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .fetch_user_achievements(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::update_activity`](struct.Discord.html#method.update_activity)
+    pub fn update_activity(self, activity: &Activity) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .update_activity(activity, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::clear_activity`](struct.Discord.html#method.clear_activity)
+    pub fn clear_activity(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.clear_activity(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::send_request_reply`](struct.Discord.html#method.send_request_reply)
+    pub fn send_request_reply(self, user_id: UserID, reply: RequestReply) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .send_request_reply(user_id, reply, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::send_invite`](struct.Discord.html#method.send_invite)
+    pub fn send_invite<'s>(
+        self,
+        user_id: UserID,
+        action: Action,
+        content: impl Into<Cow<'s, str>>,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .send_invite(user_id, action, content, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::accept_invite`](struct.Discord.html#method.accept_invite)
+    pub fn accept_invite(self, user_id: UserID) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .accept_invite(user_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::validate_or_exit`](struct.Discord.html#method.validate_or_exit)
+    pub fn validate_or_exit(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.validate_or_exit(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::oauth2_token`](struct.Discord.html#method.oauth2_token)
+    pub fn oauth2_token(self) -> DiscordFuture<OAuth2Token> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .oauth2_token(|_, res| resolver.resolve(res.map(ToOwned::to_owned)));
+        future
+    }
+
+    /// [`Discord::app_ticket`](struct.Discord.html#method.app_ticket)
+    pub fn app_ticket(self) -> DiscordFuture<String> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .app_ticket(|_, res| resolver.resolve(res.map(ToString::to_string)));
+        future
+    }
+
+    /// [`Discord::fetch_image`](struct.Discord.html#method.fetch_image)
+    pub fn fetch_image(
+        self,
+        handle: ImageHandle,
+        refresh: FetchKind,
+    ) -> DiscordFuture<ImageHandle> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .fetch_image(handle, refresh, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::create_lobby`](struct.Discord.html#method.create_lobby)
+    pub fn create_lobby(self, transaction: &LobbyTransaction) -> DiscordFuture<Lobby> {
+        let (resolver, future) = discord_future::channel();
+        self.0.create_lobby(transaction, |_, res| {
+            resolver.resolve(res.map(ToOwned::to_owned))
+        });
+        future
+    }
+
+    /// [`Discord::update_lobby`](struct.Discord.html#method.update_lobby)
+    pub fn update_lobby(
+        self,
+        lobby_id: LobbyID,
+        transaction: &LobbyTransaction,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .update_lobby(lobby_id, transaction, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::delete_lobby`](struct.Discord.html#method.delete_lobby)
+    pub fn delete_lobby(self, lobby_id: LobbyID) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .delete_lobby(lobby_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::connect_lobby`](struct.Discord.html#method.connect_lobby)
+    pub fn connect_lobby<'s>(
+        self,
+        lobby_id: LobbyID,
+        secret: impl Into<Cow<'s, str>>,
+    ) -> DiscordFuture<Lobby> {
+        let (resolver, future) = discord_future::channel();
+        self.0.connect_lobby(lobby_id, secret, |_, res| {
+            resolver.resolve(res.map(ToOwned::to_owned))
+        });
+        future
+    }
+
+    /// [`Discord::connect_lobby_with_activity_secret`](struct.Discord.html#method.connect_lobby_with_activity_secret)
+    pub fn connect_lobby_with_activity_secret<'s>(
+        self,
+        activity_secret: impl Into<Cow<'s, str>>,
+    ) -> DiscordFuture<Lobby> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .connect_lobby_with_activity_secret(activity_secret, |_, res| {
+                resolver.resolve(res.map(ToOwned::to_owned))
+            });
+        future
+    }
+
+    /// [`Discord::disconnect_lobby`](struct.Discord.html#method.disconnect_lobby)
+    pub fn disconnect_lobby(self, lobby_id: LobbyID) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .disconnect_lobby(lobby_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::update_member`](struct.Discord.html#method.update_member)
+    pub fn update_member(
+        self,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        transaction: &LobbyMemberTransaction,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .update_member(lobby_id, user_id, transaction, |_, res| {
+                resolver.resolve(res)
+            });
+        future
+    }
+
+    /// [`Discord::send_lobby_message`](struct.Discord.html#method.send_lobby_message)
+    pub fn send_lobby_message(
+        self,
+        lobby_id: LobbyID,
+        buffer: impl AsRef<[u8]>,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .send_lobby_message(lobby_id, buffer, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::lobby_search`](struct.Discord.html#method.lobby_search)
+    pub fn lobby_search(self, search: &SearchQuery) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.lobby_search(search, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::connect_lobby_voice`](struct.Discord.html#method.connect_lobby_voice)
+    pub fn connect_lobby_voice(self, lobby_id: LobbyID) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .connect_lobby_voice(lobby_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::disconnect_lobby_voice`](struct.Discord.html#method.disconnect_lobby_voice)
+    pub fn disconnect_lobby_voice(self, lobby_id: LobbyID) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .disconnect_lobby_voice(lobby_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::set_overlay_opened`](struct.Discord.html#method.set_overlay_opened)
+    pub fn set_overlay_opened(self, opened: bool) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .set_overlay_opened(opened, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::open_invite_overlay`](struct.Discord.html#method.open_invite_overlay)
+    pub fn open_invite_overlay(self, action: Action) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .open_invite_overlay(action, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::open_guild_invite_overlay`](struct.Discord.html#method.open_guild_invite_overlay)
+    pub fn open_guild_invite_overlay<'s>(self, code: impl Into<Cow<'s, str>>) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .open_guild_invite_overlay(code, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::open_voice_settings`](struct.Discord.html#method.open_voice_settings)
+    pub fn open_voice_settings(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.open_voice_settings(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::read_file_async`](struct.Discord.html#method.read_file_async)
+    pub fn read_file_async<'s>(self, filename: impl Into<Cow<'s, str>>) -> DiscordFuture<Vec<u8>> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .read_file_async(filename, |_, res| resolver.resolve(res.map(<[u8]>::to_vec)));
+        future
+    }
+
+    /// [`Discord::read_file_async_partial`](struct.Discord.html#method.read_file_async_partial)
+    pub fn read_file_async_partial<'s>(
+        self,
+        filename: impl Into<Cow<'s, str>>,
+        offset: u64,
+        length: u64,
+    ) -> DiscordFuture<Vec<u8>> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .read_file_async_partial(filename, offset, length, |_, res| {
+                resolver.resolve(res.map(<[u8]>::to_vec))
+            });
+        future
+    }
+
+    /// [`Discord::write_file_async`](struct.Discord.html#method.write_file_async)
+    pub fn write_file_async<'s>(
+        self,
+        filename: impl Into<Cow<'s, str>>,
+        buffer: impl AsRef<[u8]>,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .write_file_async(filename, buffer, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::fetch_skus`](struct.Discord.html#method.fetch_skus)
+    pub fn fetch_skus(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.fetch_skus(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::fetch_entitlements`](struct.Discord.html#method.fetch_entitlements)
+    pub fn fetch_entitlements(self) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0.fetch_entitlements(|_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::start_purchase`](struct.Discord.html#method.start_purchase)
+    pub fn start_purchase(self, sku_id: Snowflake) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .start_purchase(sku_id, |_, res| resolver.resolve(res));
+        future
+    }
+
+    /// [`Discord::user`](struct.Discord.html#method.user)
+    pub fn user(self, user_id: UserID) -> DiscordFuture<User> {
+        let (resolver, future) = discord_future::channel();
+        self.0.user(user_id, |_, res| {
+            resolver.resolve(res.map(ToOwned::to_owned))
+        });
+        future
+    }
+
+    /// [`Discord::set_input_mode`](struct.Discord.html#method.set_input_mode)
+    pub fn set_input_mode(self, input_mode: InputMode) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.0
+            .set_input_mode(input_mode, |_, res| resolver.resolve(res));
+        future
+    }
+}
@@ -1,5 +1,6 @@
 /// Image with pixel data
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     pub(crate) width: u32,
     pub(crate) height: u32,
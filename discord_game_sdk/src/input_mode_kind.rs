@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/discord-voice#data-models-inputmodetype-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputModeKind {
     /// Voice is transmitted when a key is pushed
     PushToTalk,
@@ -0,0 +1,315 @@
+use crate::{
+    discord_future::{self, DiscordFuture},
+    Action, Activity, Discord, Event, RelationshipKind, RequestReply, Result, User, UserID,
+};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Keeps incoming join requests and invites until they are answered
+///
+/// [`on_activity_join_request`] and [`on_activity_invite`] only fire once, this records them
+/// with the time they were received, to be listed by a UI and answered later through
+/// [`answer_join_request`] and [`answer_invite`].
+///
+/// A repeated join request or invite from the same user replaces the previous one.
+/// Entries expire after 5 minutes by default, they are then no longer listed nor answered.
+///
+/// Join requests and invites from friends, and from other users, can be answered automatically
+/// on arrival instead of being recorded, see [`with_friend_reply`] and [`with_stranger_reply`].
+/// Errors of automatic answers are logged.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+/// let mut inbox = InviteInbox::new();
+/// inbox
+///     .with_friend_reply(Some(RequestReply::Yes))
+///     .with_stranger_reply(Some(RequestReply::Ignore));
+///
+/// discord.set_event_buffering(true);
+///
+/// loop {
+///     let events = discord.poll_events()?.collect::<Vec<_>>();
+///
+///     for event in &events {
+///         inbox.update(&discord, event);
+///     }
+///
+///     for entry in inbox.pending() {
+///         println!("{} ({:?} ago)", entry.user().username(), entry.received_at().elapsed());
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`answer_invite`]: #method.answer_invite
+/// [`answer_join_request`]: #method.answer_join_request
+/// [`on_activity_invite`]: trait.EventHandler.html#method.on_activity_invite
+/// [`on_activity_join_request`]: trait.EventHandler.html#method.on_activity_join_request
+/// [`with_friend_reply`]: #method.with_friend_reply
+/// [`with_stranger_reply`]: #method.with_stranger_reply
+pub struct InviteInbox {
+    expiry: Duration,
+    friend_reply: Option<RequestReply>,
+    stranger_reply: Option<RequestReply>,
+    entries: Vec<InboxEntry>,
+}
+
+/// Join request or invite waiting for an answer, see [`InviteInbox`](struct.InviteInbox.html)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InboxEntry {
+    user: User,
+    invite: Option<(Action, Activity)>,
+    received_at: Instant,
+}
+
+impl InboxEntry {
+    /// The user who asked to join, or who sent the invite
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Whether the user asks to join the game of the current user
+    pub fn is_join_request(&self) -> bool {
+        self.invite.is_none()
+    }
+
+    /// What the invite is for, `None` for join requests
+    pub fn action(&self) -> Option<Action> {
+        self.invite.as_ref().map(|(action, _)| *action)
+    }
+
+    /// The activity the user invites to, `None` for join requests
+    pub fn activity(&self) -> Option<&Activity> {
+        self.invite.as_ref().map(|(_, activity)| activity)
+    }
+
+    /// When the latest join request or invite was received
+    pub fn received_at(&self) -> Instant {
+        self.received_at
+    }
+}
+
+impl InviteInbox {
+    /// Creates an empty inbox, answering nothing automatically.
+    pub fn new() -> Self {
+        Self {
+            expiry: Duration::from_secs(5 * 60),
+            friend_reply: None,
+            stranger_reply: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// How long entries are kept
+    pub fn with_expiry(&mut self, value: Duration) -> &mut Self {
+        self.expiry = value;
+        self
+    }
+
+    /// Answer to give automatically to friends, `None` to record their join requests and invites
+    ///
+    /// For invites, `Yes` accepts them, `No` and `Ignore` drop them.
+    pub fn with_friend_reply(&mut self, value: Option<RequestReply>) -> &mut Self {
+        self.friend_reply = value;
+        self
+    }
+
+    /// Answer to give automatically to users who are not friends, `None` to record their join
+    /// requests and invites
+    ///
+    /// For invites, `Yes` accepts them, `No` and `Ignore` drop them.
+    pub fn with_stranger_reply(&mut self, value: Option<RequestReply>) -> &mut Self {
+        self.stranger_reply = value;
+        self
+    }
+
+    /// Entries waiting for an answer, oldest first
+    pub fn pending(&self) -> impl '_ + Iterator<Item = &InboxEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.received_at.elapsed() < self.expiry)
+    }
+
+    /// Records [`Event::ActivityJoinRequest`] and [`Event::ActivityInvite`], other events are
+    /// ignored.
+    ///
+    /// [`Event::ActivityInvite`]: enum.Event.html#variant.ActivityInvite
+    /// [`Event::ActivityJoinRequest`]: enum.Event.html#variant.ActivityJoinRequest
+    pub fn update<E>(&mut self, discord: &Discord<'_, E>, event: &Event) {
+        match event {
+            Event::ActivityJoinRequest { user } => self.receive_join_request(discord, user),
+            Event::ActivityInvite {
+                kind,
+                user,
+                activity,
+            } => self.receive_invite(discord, *kind, user, activity),
+            _ => {}
+        }
+    }
+
+    /// Records a join request, to call from
+    /// [`on_activity_join_request`](trait.EventHandler.html#method.on_activity_join_request).
+    pub fn receive_join_request<E>(&mut self, discord: &Discord<'_, E>, user: &User) {
+        self.receive(discord, user.clone(), None);
+    }
+
+    /// Records an invite, to call from
+    /// [`on_activity_invite`](trait.EventHandler.html#method.on_activity_invite).
+    pub fn receive_invite<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        action: Action,
+        user: &User,
+        activity: &Activity,
+    ) {
+        self.receive(discord, user.clone(), Some((action, activity.clone())));
+    }
+
+    /// Answers the pending join request of a user with
+    /// [`send_request_reply`](struct.Discord.html#method.send_request_reply), and removes it.
+    ///
+    /// Returns `None` if no join request from this user is pending.
+    pub fn answer_join_request<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        user_id: UserID,
+        reply: RequestReply,
+    ) -> Option<DiscordFuture<()>> {
+        let entry = self.take(user_id, true)?;
+        Some(answer(discord, &entry, reply))
+    }
+
+    /// Accepts the pending invite of a user with
+    /// [`accept_invite`](struct.Discord.html#method.accept_invite), or drops it, and removes it.
+    ///
+    /// Returns `None` if no invite from this user is pending.
+    pub fn answer_invite<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        user_id: UserID,
+        accept: bool,
+    ) -> Option<DiscordFuture<()>> {
+        let entry = self.take(user_id, false)?;
+        let reply = if accept {
+            RequestReply::Yes
+        } else {
+            RequestReply::Ignore
+        };
+
+        Some(answer(discord, &entry, reply))
+    }
+
+    fn receive<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        user: User,
+        invite: Option<(Action, Activity)>,
+    ) {
+        self.expire();
+
+        let entry = InboxEntry {
+            user,
+            invite,
+            received_at: Instant::now(),
+        };
+
+        let is_friend = match discord.relationship_with(entry.user.id()) {
+            Ok(relationship) => relationship.kind() == RelationshipKind::Friend,
+            Err(_) => false,
+        };
+
+        let reply = if is_friend {
+            self.friend_reply
+        } else {
+            self.stranger_reply
+        };
+
+        if let Some(reply) = reply {
+            let user_id = entry.user.id();
+
+            send_answer(discord, &entry, reply, move |result| {
+                if let Err(error) = result {
+                    log::warn!("failed to answer user {} automatically: {}", user_id, error);
+                }
+            });
+
+            return;
+        }
+
+        let existing = self.entries.iter_mut().find(|existing| {
+            existing.user.id() == entry.user.id()
+                && existing.is_join_request() == entry.is_join_request()
+        });
+
+        match existing {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    fn take(&mut self, user_id: UserID, is_join_request: bool) -> Option<InboxEntry> {
+        self.expire();
+
+        let index = self.entries.iter().position(|entry| {
+            entry.user.id() == user_id && entry.is_join_request() == is_join_request
+        })?;
+
+        Some(self.entries.remove(index))
+    }
+
+    fn expire(&mut self) {
+        let expiry = self.expiry;
+        self.entries
+            .retain(|entry| entry.received_at.elapsed() < expiry);
+    }
+}
+
+impl Default for InviteInbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for InviteInbox {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("InviteInbox")
+            .field("expiry", &self.expiry)
+            .field("friend_reply", &self.friend_reply)
+            .field("stranger_reply", &self.stranger_reply)
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+// Declined invites are only dropped, the SDK has no call for them
+fn send_answer<'d, E>(
+    discord: &Discord<'d, E>,
+    entry: &InboxEntry,
+    reply: RequestReply,
+    callback: impl 'd + FnOnce(Result<()>),
+) {
+    let user_id = entry.user.id();
+
+    if entry.is_join_request() {
+        discord.send_request_reply(user_id, reply, |_, result| callback(result));
+    } else if reply == RequestReply::Yes {
+        discord.accept_invite(user_id, |_, result| callback(result));
+    } else {
+        callback(Ok(()));
+    }
+}
+
+fn answer<E>(
+    discord: &Discord<'_, E>,
+    entry: &InboxEntry,
+    reply: RequestReply,
+) -> DiscordFuture<()> {
+    let (resolver, future) = discord_future::channel();
+    send_answer(discord, entry, reply, move |result| {
+        resolver.resolve(result)
+    });
+    future
+}
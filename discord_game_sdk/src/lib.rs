@@ -63,6 +63,38 @@
 //! This allows for `cargo run` to function.
 //!
 //!
+//! ### `archive`
+//!
+//! Delegates to `discord_game_sdk_sys/archive`.
+//!
+//! Extracts the SDK from the local `.zip` archive set in `DISCORD_GAME_SDK_ZIP`
+//! instead of using `DISCORD_GAME_SDK_PATH`, without reaching the network.
+//! The build fails if `DISCORD_GAME_SDK_SHA256` is set and doesn't match the archive.
+//!
+//!
+//! ### `prebuilt-bindings`
+//!
+//! Delegates to `discord_game_sdk_sys/prebuilt-bindings`.
+//!
+//! Uses bindings checked into `discord_game_sdk_sys` instead of generating them with `bindgen`,
+//! libclang and the SDK headers are then not required. Combine with `mock` and
+//! `default-features = false` to build and test without downloading the SDK.
+//!
+//! Bindings are available for 64-bit targets and SDK version `2.5.6`,
+//! `DISCORD_GAME_SDK_BINDINGS` selects the version and enables this mode without the feature.
+//!
+//!
+//! ### `dynamic`
+//!
+//! Optional, loads the SDK library at runtime with [`libloading`](https://docs.rs/libloading)
+//! instead of linking to it, combine with `default-features = false`.
+//!
+//! The library doesn't need to be renamed or present when building.
+//! [`Discord::new`](struct.Discord.html#method.new) looks for it next to the executable,
+//! then in the system's library search path, and returns `Err(LibraryNotFound)` if it is missing.
+//! [`Discord::with_library`](struct.Discord.html#method.with_library) loads it from a given path.
+//!
+//!
 //! ### [`image`](https://docs.rs/image)
 //!
 //! Optional crate.
@@ -70,6 +102,43 @@
 //! Provides a conversion from our `Image` to `image::RgbaImage`.
 //!
 //!
+//! ### [`serde`](https://docs.rs/serde)
+//!
+//! Optional crate.
+//!
+//! Implements `Serialize` and `Deserialize` for data types such as `Activity`, `Lobby`, `User`
+//! and `Event`, using their fields as returned by their getters.
+//! Deserializing fails for strings that don't fit in the SDK's buffers.
+//!
+//! Provides typed lobby and member metadata, such as
+//! [`update_lobby_typed`](struct.Discord.html#method.update_lobby_typed).
+//!
+//!
+//! ### `secrets`
+//!
+//! Optional, provides [`SecretCodec`](struct.SecretCodec.html), packing lobbies, party IDs,
+//! an expiry and custom claims into join and spectate secrets signed with HMAC-SHA256,
+//! using [`hmac`](https://docs.rs/hmac), [`sha2`](https://docs.rs/sha2) and [`base64`](https://docs.rs/base64).
+//!
+//!
+//! ### `mock`
+//!
+//! Provides [`MockBackend`](struct.MockBackend.html), an in-process fake of the Discord client,
+//! and [`Discord::mock`](struct.Discord.html#method.mock) to create instances backed by it.
+//!
+//! Useful for testing without the SDK library or a running client,
+//! combine with `default-features = false` to avoid linking.
+//!
+//! `discord_game_sdk_stub` builds a library exporting `DiscordCreate` backed by it,
+//! to test the FFI calls made through the `link` feature without a Discord client.
+//!
+//!
+//! # Breaking Changes
+//!
+//! `Error` is no longer `Copy`, as `Error::Context` carries the arguments of the failed call.
+//! Clone errors to use them more than once, and compare `Error::kind` to match them.
+//!
+//!
 //! # Safety
 //!
 //! This crate relies on the SDK to provide correct data and behavior:
@@ -116,41 +185,74 @@
 
 mod action;
 mod activity;
+mod activity_builder;
+mod activity_error;
 mod activity_kind;
+#[cfg(feature = "secrets")]
+mod activity_secret;
 mod aliases;
 mod cast;
 mod comparison;
+mod connection_state;
 mod create_flags;
 mod discord;
+mod discord_driver;
+mod discord_future;
+mod discord_handle;
 mod distance;
 mod entitlement;
 mod entitlement_kind;
 mod error;
+mod error_context;
+mod event;
 mod event_handler;
 pub(crate) mod events;
 mod fetch_kind;
 mod file_stat;
+mod futures;
 mod image;
 mod image_handle;
 mod image_kind;
 mod input_mode;
 mod input_mode_kind;
+mod invite_inbox;
 pub(crate) mod iter;
 mod lobby;
 mod lobby_kind;
 mod lobby_member_transaction;
+mod lobby_spec;
 mod lobby_transaction;
+#[cfg(feature = "serde")]
+mod metadata;
+#[cfg(feature = "serde")]
+mod metadata_error;
 mod oauth2_token;
 mod premium_kind;
 mod presence;
+mod presence_manager;
+mod presence_template;
 mod relationship;
 mod relationship_kind;
 mod reliability;
 mod request_reply;
+mod retry;
+mod retry_policy;
+mod scheduler;
+mod sdk_versions;
 mod search_query;
+#[cfg(feature = "secrets")]
+mod secret_codec;
+#[cfg(feature = "secrets")]
+mod secret_error;
+#[cfg(feature = "serde")]
+mod serialization;
 mod sku;
 mod sku_kind;
+mod state_change;
+mod state_mirror;
 mod status;
+mod supervisor;
+mod template_error;
 mod to_result;
 mod user;
 mod user_achievement;
@@ -165,6 +267,8 @@ mod methods {
     mod applications;
     mod images;
     mod lobbies;
+    #[cfg(feature = "serde")]
+    mod metadata;
     mod networking;
     mod overlay;
     mod relationships;
@@ -174,9 +278,11 @@ mod methods {
     mod voice;
 
     mod callback;
+    mod futures;
+    mod retry;
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 mod mock;
 
 pub(crate) use discord_game_sdk_sys as sys;
@@ -184,40 +290,72 @@ pub(crate) use discord_game_sdk_sys as sys;
 pub use self::{
     action::Action,
     activity::Activity,
+    activity_builder::ActivityBuilder,
+    activity_error::{ActivityError, InvalidField},
     activity_kind::ActivityKind,
     aliases::*,
     cast::Cast,
     comparison::Comparison,
+    connection_state::ConnectionState,
     create_flags::CreateFlags,
     discord::Discord,
+    discord_driver::DiscordDriver,
+    discord_future::DiscordFuture,
+    discord_handle::DiscordHandle,
     distance::Distance,
     entitlement::Entitlement,
     entitlement_kind::EntitlementKind,
     error::{Error, Result},
+    error_context::ErrorContext,
+    event::Event,
     event_handler::EventHandler,
     fetch_kind::FetchKind,
     file_stat::FileStat,
+    futures::Futures,
     image::Image,
     image_handle::ImageHandle,
     image_kind::ImageKind,
     input_mode::InputMode,
     input_mode_kind::InputModeKind,
+    invite_inbox::{InboxEntry, InviteInbox},
     lobby::Lobby,
     lobby_kind::LobbyKind,
     lobby_member_transaction::LobbyMemberTransaction,
+    lobby_spec::LobbySpec,
     lobby_transaction::LobbyTransaction,
     oauth2_token::OAuth2Token,
     premium_kind::PremiumKind,
     presence::Presence,
+    presence_manager::PresenceManager,
+    presence_template::PresenceTemplate,
     relationship::Relationship,
     relationship_kind::RelationshipKind,
     reliability::Reliability,
     request_reply::RequestReply,
+    retry::Retry,
+    retry_policy::RetryPolicy,
+    scheduler::Scheduler,
+    sdk_versions::{sdk_versions, SdkVersions},
     search_query::SearchQuery,
     sku::Sku,
     sku_kind::SkuKind,
+    state_change::StateChange,
+    state_mirror::{MirroredLobby, StateMirror},
     status::Status,
+    supervisor::Supervisor,
+    template_error::TemplateError,
     user::User,
     user_achievement::UserAchievement,
     user_flags::UserFlags,
 };
+
+#[cfg(feature = "mock")]
+pub use self::mock::MockBackend;
+
+#[cfg(feature = "serde")]
+pub use self::metadata_error::MetadataError;
+
+#[cfg(feature = "secrets")]
+pub use self::{
+    activity_secret::ActivitySecret, secret_codec::SecretCodec, secret_error::SecretError,
+};
@@ -45,7 +45,7 @@ impl LobbyMemberTransaction {
     /// A nul byte will be appended to `key` if one is not present.
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#lobbymembertransactiondeletemetadata)
-    pub fn delete_metadata<S>(&mut self, mut key: String) -> &mut Self {
+    pub fn delete_metadata(&mut self, mut key: String) -> &mut Self {
         if !key.ends_with('\0') {
             key.push('\0')
         }
@@ -54,6 +54,29 @@ impl LobbyMemberTransaction {
         self
     }
 
+    /// Sets one metadata value per field of `value` for the user, see
+    /// [`update_member_typed`](struct.Discord.html#method.update_member_typed)
+    ///
+    /// Only available with the `serde` feature.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Err` without changing the transaction if `value` can't be stored in metadata.
+    #[cfg(feature = "serde")]
+    pub fn add_typed_metadata<T: serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<&mut Self, crate::MetadataError> {
+        for (key, value) in crate::metadata::to_metadata(value)? {
+            match value {
+                Some(value) => self.add_metadata(key, value),
+                None => self.delete_metadata(key),
+            };
+        }
+
+        Ok(self)
+    }
+
     pub(crate) unsafe fn process(
         &self,
         tx: *mut sys::IDiscordLobbyMemberTransaction,
@@ -0,0 +1,123 @@
+use crate::{Lobby, LobbyKind, LobbyTransaction, UserID};
+use std::collections::BTreeMap;
+
+/// Desired state of a lobby, see [`reconcile_lobby`](struct.Discord.html#method.reconcile_lobby)
+///
+/// Properties that are not set are left unchanged. The metadata is the complete desired
+/// metadata: entries of the lobby that are not in the spec are deleted.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(discord: Discord<'_, ()>, lobby_id: LobbyID, players: u32) {
+/// let spec = LobbySpec::new()
+///     .capacity(4)
+///     .locked(players == 4)
+///     .add_metadata("map".into(), "de_dust2".into())
+///     .clone();
+///
+/// // Only sends what changed, if anything
+/// discord.reconcile_lobby(lobby_id, &spec, |_, result| match result {
+///     Ok(true) => println!("lobby updated"),
+///     Ok(false) => {}
+///     Err(error) => eprintln!("failed to update lobby: {}", error),
+/// });
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LobbySpec {
+    kind: Option<LobbyKind>,
+    owner: Option<UserID>,
+    capacity: Option<u32>,
+    locked: Option<bool>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl LobbySpec {
+    /// Creates a spec leaving every property unchanged, and without metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the lobby should be private or public
+    pub fn kind(&mut self, kind: LobbyKind) -> &mut Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// The ID of the user who should own the lobby
+    pub fn owner(&mut self, user_id: UserID) -> &mut Self {
+        self.owner = Some(user_id);
+        self
+    }
+
+    /// The maximum amount of players that can join
+    pub fn capacity(&mut self, capacity: u32) -> &mut Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Whether the lobby should be locked, new users cannot join a locked lobby
+    pub fn locked(&mut self, locked: bool) -> &mut Self {
+        self.locked = Some(locked);
+        self
+    }
+
+    /// Adds a metadata entry the lobby should have
+    pub fn add_metadata(&mut self, key: String, value: String) -> &mut Self {
+        let _ = self.metadata.insert(key, value);
+        self
+    }
+
+    // The transaction bringing the lobby to the spec, `None` if it already matches
+    pub(crate) fn diff(
+        &self,
+        lobby: &Lobby,
+        metadata: &BTreeMap<String, String>,
+    ) -> Option<LobbyTransaction> {
+        let mut transaction = LobbyTransaction::new();
+        let mut changed = false;
+
+        if let Some(kind) = self.kind.filter(|&kind| kind != lobby.kind()) {
+            transaction.kind(kind);
+            changed = true;
+        }
+
+        if let Some(owner) = self.owner.filter(|&owner| owner != lobby.owner_id()) {
+            transaction.owner(owner);
+            changed = true;
+        }
+
+        if let Some(capacity) = self
+            .capacity
+            .filter(|&capacity| capacity != lobby.capacity())
+        {
+            transaction.capacity(capacity);
+            changed = true;
+        }
+
+        if let Some(locked) = self.locked.filter(|&locked| locked != lobby.locked()) {
+            transaction.locked(locked);
+            changed = true;
+        }
+
+        for (key, value) in &self.metadata {
+            if metadata.get(key) != Some(value) {
+                transaction.add_metadata(key.clone(), value.clone());
+                changed = true;
+            }
+        }
+
+        for key in metadata.keys() {
+            if !self.metadata.contains_key(key) {
+                transaction.delete_metadata(key.clone());
+                changed = true;
+            }
+        }
+
+        if changed {
+            Some(transaction)
+        } else {
+            None
+        }
+    }
+}
@@ -73,7 +73,7 @@ impl LobbyTransaction {
     /// A nul byte will be appended to `key` if one is not present.
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#lobbytransactiondeletemetadata)
-    pub fn delete_metadata<S>(&mut self, mut key: String) -> &mut Self {
+    pub fn delete_metadata(&mut self, mut key: String) -> &mut Self {
         if !key.ends_with('\0') {
             key.push('\0')
         }
@@ -82,6 +82,29 @@ impl LobbyTransaction {
         self
     }
 
+    /// Sets one metadata value per field of `value` for the lobby, see
+    /// [`update_lobby_typed`](struct.Discord.html#method.update_lobby_typed)
+    ///
+    /// Only available with the `serde` feature.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Err` without changing the transaction if `value` can't be stored in metadata.
+    #[cfg(feature = "serde")]
+    pub fn add_typed_metadata<T: serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<&mut Self, crate::MetadataError> {
+        for (key, value) in crate::metadata::to_metadata(value)? {
+            match value {
+                Some(value) => self.add_metadata(key, value),
+                None => self.delete_metadata(key),
+            };
+        }
+
+        Ok(self)
+    }
+
     /// Sets whether the lobby is locked or not. When locked, new users cannot join
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#lobbytransactionsetlocked)
@@ -0,0 +1,458 @@
+use crate::MetadataError;
+use serde::{
+    de::{self, IntoDeserializer},
+    ser::{self, Impossible},
+    Deserialize, Serialize,
+};
+use std::collections::{btree_map, BTreeMap};
+
+// Structs and maps become one entry per field, numbers are written in decimal so that
+// `Cast::Number` search filters and sorts apply to them, `None` deletes the entry
+pub(crate) fn to_metadata<T: Serialize>(
+    value: &T,
+) -> Result<Vec<(String, Option<String>)>, MetadataError> {
+    value.serialize(EntriesSerializer)
+}
+
+pub(crate) fn from_metadata<'de, T: Deserialize<'de>>(
+    metadata: &'de BTreeMap<String, String>,
+) -> Result<T, MetadataError> {
+    T::deserialize(EntriesDeserializer(metadata))
+}
+
+macro_rules! unsupported {
+    ($($method:ident($($arg:ty),*) => $kind:expr,)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+                Err(MetadataError::Unsupported($kind))
+            }
+        )*
+    };
+}
+
+// Serializes structs and maps, the top level
+struct EntriesSerializer;
+
+struct Entries {
+    entries: Vec<(String, Option<String>)>,
+    key: Option<String>,
+}
+
+impl ser::Serializer for EntriesSerializer {
+    type Ok = Vec<(String, Option<String>)>;
+    type Error = MetadataError;
+
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Entries;
+    type SerializeStruct = Entries;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    unsupported! {
+        serialize_bool(bool) => "bool",
+        serialize_i8(i8) => "number",
+        serialize_i16(i16) => "number",
+        serialize_i32(i32) => "number",
+        serialize_i64(i64) => "number",
+        serialize_u8(u8) => "number",
+        serialize_u16(u16) => "number",
+        serialize_u32(u32) => "number",
+        serialize_u64(u64) => "number",
+        serialize_f32(f32) => "number",
+        serialize_f64(f64) => "number",
+        serialize_char(char) => "char",
+        serialize_str(&str) => "string",
+        serialize_bytes(&[u8]) => "bytes",
+        serialize_none() => "option",
+        serialize_unit() => "unit",
+        serialize_unit_struct(&'static str) => "unit struct",
+        serialize_unit_variant(&'static str, u32, &'static str) => "enum",
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(MetadataError::Unsupported("sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(MetadataError::Unsupported("tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(MetadataError::Unsupported("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Entries {
+            entries: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+}
+
+impl ser::SerializeMap for Entries {
+    type Ok = Vec<(String, Option<String>)>;
+    type Error = MetadataError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(ValueSerializer)? {
+            Some(key) => self.key = Some(key),
+            None => return Err(MetadataError::Unsupported("option key")),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().expect("serialize_key was called");
+        let value = value.serialize(ValueSerializer)?;
+        self.entries.push((key, value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.entries)
+    }
+}
+
+impl ser::SerializeStruct for Entries {
+    type Ok = Vec<(String, Option<String>)>;
+    type Error = MetadataError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let value = value.serialize(ValueSerializer)?;
+        self.entries.push((key.to_string(), value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.entries)
+    }
+}
+
+// Serializes the value of an entry, `None` for options that are `None`
+struct ValueSerializer;
+
+macro_rules! display {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(Some(value.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Option<String>;
+    type Error = MetadataError;
+
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    unsupported! {
+        serialize_bytes(&[u8]) => "bytes",
+        serialize_unit() => "unit",
+        serialize_unit_struct(&'static str) => "unit struct",
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(MetadataError::Unsupported("sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(MetadataError::Unsupported("tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(MetadataError::Unsupported("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(MetadataError::Unsupported("nested map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(MetadataError::Unsupported("nested struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(MetadataError::Unsupported("enum"))
+    }
+}
+
+// Deserializes structs and maps from every entry
+struct EntriesDeserializer<'de>(&'de BTreeMap<String, String>);
+
+impl<'de> de::Deserializer<'de> for EntriesDeserializer<'de> {
+    type Error = MetadataError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(EntriesAccess {
+            entries: self.0.iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct EntriesAccess<'de> {
+    entries: btree_map::Iter<'de, String, String>,
+    value: Option<(&'de str, &'de str)>,
+}
+
+impl<'de> de::MapAccess<'de> for EntriesAccess<'de> {
+    type Error = MetadataError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(key.as_str().into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = self.value.take().expect("next_key_seed was called");
+
+        seed.deserialize(ValueDeserializer(value))
+            .map_err(|error| match error {
+                MetadataError::Custom(message) => MetadataError::InvalidValue {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    message,
+                },
+                error => error,
+            })
+    }
+}
+
+// Deserializes the value of an entry, parsing numbers and booleans
+struct ValueDeserializer<'de>(&'de str);
+
+macro_rules! parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(error) => Err(de::Error::custom(error)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = MetadataError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    // Present entries are never `None`
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,77 @@
+use crate::Error;
+use serde::{de, ser};
+use std::fmt;
+
+/// Error returned when writing or reading typed lobby and member metadata
+///
+/// See [`update_lobby_typed`](struct.Discord.html#method.update_lobby_typed) and
+/// [`lobby_metadata_typed`](struct.Discord.html#method.lobby_metadata_typed).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MetadataError {
+    /// Reading the metadata failed
+    Discord(Error),
+
+    /// The key is missing from the metadata
+    MissingKey(String),
+
+    /// The value of the key could not be parsed
+    InvalidValue {
+        key: String,
+        value: String,
+        message: String,
+    },
+
+    /// The type can't be stored in metadata, only structs and maps of strings, numbers, booleans,
+    /// unit enum variants and options are supported
+    Unsupported(&'static str),
+
+    /// Other error reported by the `Serialize` or `Deserialize` implementation
+    Custom(String),
+}
+
+impl From<Error> for MetadataError {
+    fn from(error: Error) -> Self {
+        Self::Discord(error)
+    }
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Discord(error) => write!(f, "{}", error),
+            Self::MissingKey(key) => write!(f, "missing metadata key {}", key),
+            Self::InvalidValue {
+                key,
+                value,
+                message,
+            } => write!(f, "invalid metadata {} = {:?}: {}", key, value, message),
+            Self::Unsupported(kind) => write!(f, "{} can't be stored in metadata", kind),
+            Self::Custom(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Discord(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ser::Error for MetadataError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+impl de::Error for MetadataError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::MissingKey(field.to_string())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{iter, sys, to_result::ToResult, Discord, Result, Snowflake, UserAchievement};
+use crate::{
+    error_context::WithContext, iter, sys, to_result::ToResult, Discord, Result, Snowflake,
+    UserAchievement,
+};
 use std::convert::TryInto;
 
 /// # Achievements
@@ -40,8 +43,16 @@ impl<'d, E> Discord<'d, E> {
     ) {
         debug_assert!((0..=100).contains(&percent_complete));
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("achievements", "set_user_achievement", || {
+            vec![
+                ("achievement_id", achievement_id.to_string()),
+                ("percent_complete", percent_complete.to_string()),
+            ]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.achievement_manager();
@@ -73,8 +84,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn fetch_user_achievements(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("achievements", "fetch_user_achievements", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.achievement_manager();
@@ -111,11 +125,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn user_achievement(&self, achievement_id: Snowflake) -> Result<UserAchievement> {
         let mut achievement = UserAchievement(sys::DiscordUserAchievement::default());
 
+        let call = self.call_context("achievements", "user_achievement", || {
+            vec![("achievement_id", achievement_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.achievement_manager();
 
             (*mgr).get_user_achievement.unwrap()(mgr, achievement_id, &mut achievement.0)
-                .to_result()?;
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(achievement)
@@ -151,6 +170,10 @@ impl<'d, E> Discord<'d, E> {
     pub fn user_achievement_at(&self, index: u32) -> Result<UserAchievement> {
         let mut achievement = UserAchievement(sys::DiscordUserAchievement::default());
 
+        let call = self.call_context("achievements", "user_achievement_at", || {
+            vec![("index", index.to_string())]
+        });
+
         unsafe {
             let mgr = self.achievement_manager();
 
@@ -160,7 +183,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut achievement.0,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(achievement)
@@ -1,4 +1,7 @@
-use crate::{sys, to_result::ToResult, Action, Activity, Discord, RequestReply, Result, UserID};
+use crate::{
+    error_context::WithContext, sys, to_result::ToResult, Action, Activity, Discord, RequestReply,
+    Result, UserID,
+};
 use std::borrow::Cow;
 
 /// # Activities
@@ -35,10 +38,16 @@ impl<'d, E> Discord<'d, E> {
             command.to_mut().push('\0')
         }
 
+        let call = self.call_context("activities", "register_launch_command", || {
+            vec![("command", command.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.activity_manager();
 
-            (*mgr).register_command.unwrap()(mgr, command.as_ptr()).to_result()
+            (*mgr).register_command.unwrap()(mgr, command.as_ptr())
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -60,10 +69,16 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn register_steam(&self, steam_game_id: u32) -> Result<()> {
+        let call = self.call_context("activities", "register_steam", || {
+            vec![("steam_game_id", steam_game_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.activity_manager();
 
-            (*mgr).register_steam.unwrap()(mgr, steam_game_id).to_result()
+            (*mgr).register_steam.unwrap()(mgr, steam_game_id)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -97,8 +112,11 @@ impl<'d, E> Discord<'d, E> {
         activity: &Activity,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("activities", "update_activity", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.activity_manager();
@@ -129,8 +147,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn clear_activity(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("activities", "clear_activity", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.activity_manager();
@@ -169,8 +190,16 @@ impl<'d, E> Discord<'d, E> {
         reply: RequestReply,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("activities", "send_request_reply", || {
+            vec![
+                ("user_id", user_id.to_string()),
+                ("reply", format!("{:?}", reply)),
+            ]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.activity_manager();
@@ -221,8 +250,16 @@ impl<'d, E> Discord<'d, E> {
             content.to_mut().push('\0')
         }
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("activities", "send_invite", || {
+            vec![
+                ("user_id", user_id.to_string()),
+                ("action", format!("{:?}", action)),
+            ]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.activity_manager();
@@ -271,8 +308,13 @@ impl<'d, E> Discord<'d, E> {
         user_id: UserID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("activities", "accept_invite", || {
+            vec![("user_id", user_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.activity_manager();
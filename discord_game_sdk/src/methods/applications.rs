@@ -1,4 +1,6 @@
-use crate::{sys, to_result::ToResult, utils, Discord, OAuth2Token, Result};
+use crate::{
+    error_context::WithContext, sys, to_result::ToResult, utils, Discord, OAuth2Token, Result,
+};
 use std::mem::size_of;
 
 /// # Applications
@@ -67,8 +69,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn validate_or_exit(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("applications", "validate_or_exit", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.application_manager();
@@ -100,11 +105,14 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn oauth2_token(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&OAuth2Token>)) {
+        let call = self.async_call_context("applications", "oauth2_token", Vec::new);
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, token: *mut sys::DiscordOAuth2Token| {
                 callback(
                     discord,
                     res.to_result()
+                        .context(&call)
                         .map(|()| unsafe { &*(token as *mut OAuth2Token) }),
                 )
             },
@@ -135,11 +143,14 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn app_ticket(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&str>)) {
+        let call = self.async_call_context("applications", "app_ticket", Vec::new);
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, string: *const u8| {
                 callback(
                     discord,
                     res.to_result()
+                        .context(&call)
                         .map(|()| unsafe { utils::charptr_to_str(string) }),
                 )
             },
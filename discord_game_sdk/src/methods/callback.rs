@@ -7,7 +7,7 @@ impl<'d, E> Discord<'d, E> {
         callback: impl 'd + FnOnce(&Discord<'d, E>, A),
     ) -> (*mut c_void, Option<unsafe extern "C" fn(*mut c_void, A)>) {
         extern "C" fn one_param_from_c<F: FnOnce(A), A>(ptr: *mut c_void, a: A) {
-            let _guard = utils::prevent_unwind();
+            // Panics are handled by `utils::guard_callback` inside of F
 
             // SAFETY:
             // lifetime of F was ellided when it was turned into a raw pointer
@@ -27,8 +27,9 @@ impl<'d, E> Discord<'d, E> {
             )
         }
 
+        let inner = self.0;
         let dref = self.ref_copy();
-        one_param_align_types(move |a| callback(&*dref, a))
+        one_param_align_types(move |a| utils::guard_callback(inner, || callback(&*dref, a)))
     }
 
     pub(crate) fn two_params<A, B>(
@@ -36,8 +37,6 @@ impl<'d, E> Discord<'d, E> {
         callback: impl 'd + FnOnce(&Discord<'d, E>, A, B),
     ) -> (*mut c_void, Option<unsafe extern "C" fn(*mut c_void, A, B)>) {
         extern "C" fn two_params_from_c<F: FnOnce(A, B), A, B>(ptr: *mut c_void, a: A, b: B) {
-            let _guard = utils::prevent_unwind();
-
             // SAFETY: see `one_param`
             let callback = unsafe { Box::from_raw(ptr as *mut F) };
             callback(a, b)
@@ -52,8 +51,9 @@ impl<'d, E> Discord<'d, E> {
             )
         }
 
+        let inner = self.0;
         let dref = self.ref_copy();
-        two_params_align_types(move |a, b| callback(&*dref, a, b))
+        two_params_align_types(move |a, b| utils::guard_callback(inner, || callback(&*dref, a, b)))
     }
 
     pub(crate) fn three_params<A, B, C>(
@@ -69,8 +69,6 @@ impl<'d, E> Discord<'d, E> {
             b: B,
             c: C,
         ) {
-            let _guard = utils::prevent_unwind();
-
             // SAFETY: see `one_param`
             let callback = unsafe { Box::from_raw(ptr as *mut F) };
             callback(a, b, c)
@@ -88,7 +86,10 @@ impl<'d, E> Discord<'d, E> {
             )
         }
 
+        let inner = self.0;
         let dref = self.ref_copy();
-        three_params_align_types(move |a, b, c| callback(&*dref, a, b, c))
+        three_params_align_types(move |a, b, c| {
+            utils::guard_callback(inner, || callback(&*dref, a, b, c))
+        })
     }
 }
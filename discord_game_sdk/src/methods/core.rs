@@ -2,9 +2,26 @@ use crate::{
     discord::{Discord, DiscordInner},
     events, sys,
     to_result::ToResult,
-    utils, ClientID, CreateFlags, EventHandler, Result,
+    utils, ClientID, CreateFlags, Error, Event, EventHandler, Result,
 };
-use std::{cell::UnsafeCell, convert::TryFrom, marker::PhantomData};
+use std::{cell::UnsafeCell, convert::TryFrom, marker::PhantomData, time::Instant};
+
+#[cfg(feature = "dynamic")]
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+// Names as shipped in the SDK archive, then as renamed for the `link` feature
+#[cfg(all(feature = "dynamic", target_os = "windows"))]
+const LIBRARY_NAMES: &[&str] = &["discord_game_sdk.dll"];
+#[cfg(all(feature = "dynamic", target_os = "macos"))]
+const LIBRARY_NAMES: &[&str] = &["discord_game_sdk.dylib", "libdiscord_game_sdk.dylib"];
+#[cfg(all(
+    feature = "dynamic",
+    not(any(target_os = "windows", target_os = "macos"))
+))]
+const LIBRARY_NAMES: &[&str] = &["discord_game_sdk.so", "libdiscord_game_sdk.so"];
 
 /// # Core
 ///
@@ -46,9 +63,133 @@ impl<E> Discord<'_, E> {
     ///
     /// SDK log messages are forwarded to [`log`](https://docs.rs/log)
     ///
+    /// With the `dynamic` feature, the SDK library is loaded from the directory of the executable,
+    /// then from the system's library search path, see [`with_library`].
+    ///
+    /// ## Errors
+    ///
+    /// With the `dynamic` feature, [`Error::LibraryNotFound`] if the library could not be loaded.
+    ///
+    /// [`Error::InvalidVersion`] if the library does not support the [`sdk_versions`] this crate
+    /// was built against, with the expected and actual versions, which are also logged.
+    ///
     /// > [`Create` in official docs](https://discordapp.com/developers/docs/game-sdk/discord#create)  
     /// > [`SetLogHook` in official docs](https://discordapp.com/developers/docs/game-sdk/discord#setloghook)
+    ///
+    /// [`with_library`]: #method.with_library
+    /// [`Error::InvalidVersion`]: enum.Error.html#variant.InvalidVersion
+    /// [`Error::LibraryNotFound`]: enum.Error.html#variant.LibraryNotFound
+    /// [`sdk_versions`]: fn.sdk_versions.html
     pub fn with_create_flags(client_id: ClientID, flags: CreateFlags) -> Result<Self>
+    where
+        E: EventHandler,
+    {
+        #[cfg(feature = "dynamic")]
+        {
+            let exe_dir = std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(Path::to_path_buf));
+
+            for name in LIBRARY_NAMES {
+                let paths = exe_dir
+                    .iter()
+                    .map(|dir| dir.join(name))
+                    .chain(Some(PathBuf::from(name)));
+
+                for path in paths {
+                    match Self::with_library(client_id, flags, &path) {
+                        Err(Error::LibraryNotFound) => continue,
+                        result => return result,
+                    }
+                }
+            }
+
+            Err(Error::LibraryNotFound)
+        }
+
+        #[cfg(not(feature = "dynamic"))]
+        Self::create(client_id, flags, |version, params, core| unsafe {
+            sys::DiscordCreate(version, params, core)
+        })
+    }
+
+    /// Creates an instance of the main interface with the SDK library loaded from `path`.
+    ///
+    /// Requires the `dynamic` feature, the library is unloaded when the instance is dropped.
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # const DISCORD_CLIENT_ID: ClientID = 0;
+    /// let discord = match Discord::<()>::with_library(
+    ///     DISCORD_CLIENT_ID,
+    ///     CreateFlags::NoRequireDiscord,
+    ///     "lib/discord_game_sdk.so",
+    /// ) {
+    ///     Ok(discord) => Some(discord),
+    ///     // run with Discord features disabled
+    ///     Err(Error::LibraryNotFound) => None,
+    ///     Err(error) => return Err(error),
+    /// };
+    /// # assert!(discord.is_none());
+    /// # Ok::<_, Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::LibraryNotFound`](enum.Error.html#variant.LibraryNotFound) if the library could
+    /// not be loaded or does not export `DiscordCreate`.
+    #[cfg(feature = "dynamic")]
+    pub fn with_library(
+        client_id: ClientID,
+        flags: CreateFlags,
+        path: impl AsRef<OsStr>,
+    ) -> Result<Self>
+    where
+        E: EventHandler,
+    {
+        type DiscordCreate = unsafe extern "C" fn(
+            sys::DiscordVersion,
+            *mut sys::DiscordCreateParams,
+            *mut *mut sys::IDiscordCore,
+        ) -> sys::EDiscordResult;
+
+        let path = path.as_ref();
+
+        let library = libloading::Library::new(path).map_err(|error| {
+            log::debug!("could not load {:?}: {}", path, error);
+            Error::LibraryNotFound
+        })?;
+
+        let discord_create = unsafe {
+            *library
+                .get::<DiscordCreate>(b"DiscordCreate\0")
+                .map_err(|error| {
+                    log::debug!("could not find `DiscordCreate` in {:?}: {}", path, error);
+                    Error::LibraryNotFound
+                })?
+        };
+
+        log::debug!("loaded library from {:?}", path);
+
+        let mut instance = Self::create(client_id, flags, |version, params, core| unsafe {
+            discord_create(version, params, core)
+        })?;
+
+        instance.inner_mut().library = Some(library);
+
+        Ok(instance)
+    }
+
+    // Shared by every constructor, `create` has the signature of `sys::DiscordCreate`
+    pub(crate) fn create(
+        client_id: ClientID,
+        flags: CreateFlags,
+        create: impl FnOnce(
+            sys::DiscordVersion,
+            *mut sys::DiscordCreateParams,
+            *mut *mut sys::IDiscordCore,
+        ) -> sys::EDiscordResult,
+    ) -> Result<Self>
     where
         E: EventHandler,
     {
@@ -68,10 +209,19 @@ impl<E> Discord<'_, E> {
         let mut instance = Discord(Box::into_raw(Box::new(DiscordInner {
             _invariant_lifetime: PhantomData,
 
-            // SAFETY: overwritten by `sys::DiscordCreate`, not deref'd until then
+            // SAFETY: overwritten by `create`, not deref'd until then
             core: std::ptr::null_mut(),
             client_id,
             event_handler: UnsafeCell::new(None),
+            event_buffering: false,
+            event_buffer: UnsafeCell::new(Vec::new()),
+            catch_panics: false,
+            panic: UnsafeCell::new(None),
+            error_context: false,
+            retries: UnsafeCell::new(Vec::new()),
+
+            #[cfg(feature = "dynamic")]
+            library: None,
 
             achievement_events: events::achievement::<E>(),
             activity_events: events::activity::<E>(),
@@ -86,15 +236,19 @@ impl<E> Discord<'_, E> {
 
         let mut params = instance.create_params(flags.into());
 
-        unsafe {
-            sys::DiscordCreate(
-                sys::DISCORD_VERSION,
-                &mut params,
-                &mut instance.inner_mut().core,
-            )
-            .to_result()?;
+        let result = create(
+            sys::DISCORD_VERSION,
+            &mut params,
+            &mut instance.inner_mut().core,
+        )
+        .to_result();
+
+        if let Err(error @ Error::InvalidVersion { .. }) = &result {
+            log::error!("the SDK library rejected our versions, {}", error);
         }
 
+        result?;
+
         log::trace!("received pointer to {:p}", instance.inner().core);
 
         instance.set_log_hook();
@@ -213,10 +367,120 @@ impl<E> Discord<'_, E> {
     ///
     /// If the Discord client was closed, [`Error::NotRunning`](enum.Error.html#variant.NotRunning) will be returned.
     ///
+    /// If a callback panicked while [`set_catch_panics`](#method.set_catch_panics) was enabled,
+    /// [`Error::CallbackPanicked`](enum.Error.html#variant.CallbackPanicked) will be returned.
+    ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/discord#runcallbacks)
     // We require &mut self to prevent calling during callbacks
     pub fn run_callbacks(&mut self) -> Result<()> {
-        unsafe { (*self.inner().core).run_callbacks.unwrap()(self.inner().core).to_result() }
+        unsafe { self.run_callbacks_unchecked() }
+    }
+
+    // Also used by `run_until`, which only holds a shared reference while polling
+    // SAFETY: must not be called during callbacks
+    pub(crate) unsafe fn run_callbacks_unchecked(&self) -> Result<()> {
+        (*self.inner().core).run_callbacks.unwrap()(self.inner().core).to_result()?;
+
+        self.run_retries(Instant::now());
+
+        // SAFETY: `panic` is only otherwise accessed during callbacks
+        match (*self.inner().panic.get()).take() {
+            Some(message) => Err(Error::CallbackPanicked(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Enables or disables catching panics in callbacks and
+    /// [`EventHandler`](trait.EventHandler.html) methods.
+    ///
+    /// By default, a panic inside of a callback aborts the process, as unwinding across FFI bounds
+    /// is undefined behavior. When enabled, the panic is caught instead, and the next call to
+    /// [`run_callbacks`] returns [`Error::CallbackPanicked`] with its message.
+    /// Other callbacks keep running, if several panic, only the first one is returned.
+    ///
+    /// A callback passed to a method is dropped once it panicked, as it is only ever called once.
+    /// The [`EventHandler`](trait.EventHandler.html) is kept if one of its methods panicked,
+    /// and is called again on the next event.
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+    /// discord.set_catch_panics(true);
+    ///
+    /// loop {
+    ///     match discord.run_callbacks() {
+    ///         Err(Error::CallbackPanicked(message)) => eprintln!("plugin crashed: {}", message),
+    ///         result => result?,
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Error::CallbackPanicked`]: enum.Error.html#variant.CallbackPanicked
+    /// [`run_callbacks`]: #method.run_callbacks
+    pub fn set_catch_panics(&mut self, enabled: bool) {
+        self.inner_mut().catch_panics = enabled;
+    }
+
+    /// Enables or disables buffering of [`Event`]s during [`run_callbacks`].
+    ///
+    /// Buffering is disabled by default, disabling it discards buffered events.
+    /// The [`EventHandler`](trait.EventHandler.html) is still called when buffering is enabled.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`run_callbacks`]: #method.run_callbacks
+    pub fn set_event_buffering(&mut self, enabled: bool) {
+        let inner = self.inner_mut();
+
+        inner.event_buffering = enabled;
+
+        if !enabled {
+            inner.event_buffer.get_mut().clear();
+        }
+    }
+
+    /// Calls [`run_callbacks`], then [`drain_events`].
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+    /// discord.set_event_buffering(true);
+    ///
+    /// loop {
+    ///     for event in discord.poll_events()? {
+    ///         match event {
+    ///             Event::ActivityJoin { secret } => {
+    ///                 // ...
+    ///             }
+    ///             Event::LobbyMessage { lobby_id, member_id, data } => {
+    ///                 // ...
+    ///             }
+    ///             _ => {}
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// See [`run_callbacks`].
+    ///
+    /// [`drain_events`]: #method.drain_events
+    /// [`run_callbacks`]: #method.run_callbacks
+    pub fn poll_events(&mut self) -> Result<std::vec::Drain<'_, Event>> {
+        self.run_callbacks()?;
+        Ok(self.drain_events())
+    }
+
+    /// Removes and returns all buffered [`Event`]s, in the order they were fired.
+    ///
+    /// Nothing is returned unless buffering was enabled with
+    /// [`set_event_buffering`](#method.set_event_buffering).
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, Event> {
+        self.inner_mut().event_buffer.get_mut().drain(..)
     }
 
     pub(crate) unsafe fn achievement_manager(&self) -> *mut sys::IDiscordAchievementManager {
@@ -0,0 +1,62 @@
+use crate::{discord_future::thread_waker, Discord, Futures, Result};
+use std::{
+    future::Future,
+    task::{Context, Poll},
+    thread,
+    time::Duration,
+};
+
+/// # Futures
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+/// let ticket = discord.run_until(|futures| futures.app_ticket())??;
+/// # Ok(()) }
+/// ```
+impl<'d, E> Discord<'d, E> {
+    /// Future-based variants of the methods taking a `callback`.
+    ///
+    /// See [`Futures`](struct.Futures.html).
+    pub fn futures(&self) -> Futures<'_, 'd, E> {
+        Futures(self)
+    }
+
+    /// Runs all pending SDK callbacks until the future returned by `future` completes.
+    ///
+    /// The future is polled whenever it is woken, callbacks are run at least every
+    /// 16 milliseconds in the meantime.
+    ///
+    /// `future` only gets [`Futures`](struct.Futures.html) to issue requests with, other methods,
+    /// such as [`event_handler`](#method.event_handler), can't be borrowed across callbacks.
+    ///
+    /// ## Errors
+    ///
+    /// If the Discord client was closed, [`Error::NotRunning`](enum.Error.html#variant.NotRunning) will be returned.
+    pub fn run_until<'s, F: Future>(
+        &'s mut self,
+        future: impl FnOnce(Futures<'s, 'd, E>) -> F,
+    ) -> Result<F::Output> {
+        // The future can only issue requests, it holds no reference to the event handler or
+        // other state mutated by callbacks, and callbacks are never run while it is being polled
+        let discord: &'s Self = self;
+
+        let mut future = Box::pin(future(discord.futures()));
+        let waker = thread_waker(thread::current());
+        let mut context = Context::from_waker(&waker);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return Ok(output);
+            }
+
+            // SAFETY: `run_until` takes `&mut self`, so this is not called during callbacks
+            unsafe {
+                discord.run_callbacks_unchecked()?;
+            }
+
+            // Returns immediately if a callback woke the future
+            thread::park_timeout(Duration::from_millis(16));
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{sys, to_result::ToResult, Discord, FetchKind, Image, ImageHandle, Result};
+use crate::{
+    error_context::WithContext, sys, to_result::ToResult, Discord, FetchKind, Image, ImageHandle,
+    Result,
+};
 use std::convert::{TryFrom, TryInto};
 
 /// # Images
@@ -33,9 +36,21 @@ impl<'d, E> Discord<'d, E> {
         refresh: FetchKind,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<ImageHandle>),
     ) {
+        let call = self.async_call_context("images", "fetch_image", || {
+            vec![
+                ("handle", format!("{:?}", handle)),
+                ("refresh", format!("{:?}", refresh)),
+            ]
+        });
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, image_handle: sys::DiscordImageHandle| {
-                callback(discord, res.to_result().map(|()| ImageHandle(image_handle)))
+                callback(
+                    discord,
+                    res.to_result()
+                        .context(&call)
+                        .map(|()| ImageHandle(image_handle)),
+                )
             },
         );
 
@@ -52,10 +67,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn image_dimensions(&self, handle: ImageHandle) -> Result<(u32, u32)> {
         let mut dimensions = sys::DiscordImageDimensions::default();
 
+        let call = self.call_context("images", "image_dimensions", || {
+            vec![("handle", format!("{:?}", handle))]
+        });
+
         unsafe {
             let mgr = self.image_manager();
 
-            (*mgr).get_dimensions.unwrap()(mgr, handle.0, &mut dimensions).to_result()?;
+            (*mgr).get_dimensions.unwrap()(mgr, handle.0, &mut dimensions)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok((dimensions.width, dimensions.height))
@@ -72,6 +93,10 @@ impl<'d, E> Discord<'d, E> {
 
         debug_assert!(u32::try_from(data.len()).is_ok());
 
+        let call = self.call_context("images", "image", || {
+            vec![("handle", format!("{:?}", handle))]
+        });
+
         unsafe {
             let mgr = self.image_manager();
 
@@ -81,7 +106,8 @@ impl<'d, E> Discord<'d, E> {
                 data.as_mut_ptr(),
                 data.len().try_into().unwrap_or(u32::max_value()),
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(Image {
@@ -1,9 +1,11 @@
 use crate::{
-    iter, sys, to_result::ToResult, utils, Discord, Lobby, LobbyID, LobbyMemberTransaction,
-    LobbyTransaction, NetworkChannelID, Reliability, Result, SearchQuery, UserID,
+    error_context::WithContext, iter, sys, to_result::ToResult, utils, Discord, Lobby, LobbyID,
+    LobbyMemberTransaction, LobbySpec, LobbyTransaction, NetworkChannelID, Reliability, Result,
+    SearchQuery, User, UserID,
 };
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     convert::{TryFrom, TryInto},
     mem::size_of,
 };
@@ -28,6 +30,8 @@ impl<'d, E> Discord<'d, E> {
         transaction: &LobbyTransaction,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&Lobby>),
     ) {
+        let call = self.async_call_context("lobbies", "create_lobby", Vec::new);
+
         let mgr = unsafe { self.lobby_manager() };
         let mut tx = std::ptr::null_mut();
 
@@ -35,18 +39,20 @@ impl<'d, E> Discord<'d, E> {
             unsafe { (*mgr).get_lobby_create_transaction.unwrap()(mgr, &mut tx).to_result() };
 
         if let Err(e) = create {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
         if let Err(e) = unsafe { transaction.process(tx) } {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, lobby: *mut sys::DiscordLobby| {
                 callback(
                     discord,
-                    res.to_result().map(|()| unsafe { &*(lobby as *mut Lobby) }),
+                    res.to_result()
+                        .context(&call)
+                        .map(|()| unsafe { &*(lobby as *mut Lobby) }),
                 )
             },
         );
@@ -63,6 +69,10 @@ impl<'d, E> Discord<'d, E> {
         transaction: &LobbyTransaction,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
+        let call = self.async_call_context("lobbies", "update_lobby", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         let mgr = unsafe { self.lobby_manager() };
         let mut tx = std::ptr::null_mut();
 
@@ -71,19 +81,54 @@ impl<'d, E> Discord<'d, E> {
         };
 
         if let Err(e) = create {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
         if let Err(e) = unsafe { transaction.process(tx) } {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe { (*mgr).update_lobby.unwrap()(mgr, lobby_id, tx, ptr, fun) }
     }
 
+    /// Updates a lobby to match a [`LobbySpec`](struct.LobbySpec.html), only sending the
+    /// properties and metadata entries that differ.
+    ///
+    /// The current state is read through [`lobby`](#method.lobby) and
+    /// [`iter_lobby_metadata`](#method.iter_lobby_metadata). When it already matches,
+    /// nothing is sent and the callback fires immediately with `Ok(false)`,
+    /// otherwise it fires with `Ok(true)` once the update completed.
+    pub fn reconcile_lobby(
+        &self,
+        lobby_id: LobbyID,
+        desired: &LobbySpec,
+        callback: impl 'd + FnOnce(&Discord<'d, E>, Result<bool>),
+    ) {
+        let current = self.lobby(lobby_id).and_then(|lobby| {
+            let metadata = self
+                .iter_lobby_metadata(lobby_id)?
+                .collect::<Result<BTreeMap<_, _>>>()?;
+
+            Ok((lobby, metadata))
+        });
+
+        let (lobby, metadata) = match current {
+            Ok(current) => current,
+            Err(e) => return callback(self, Err(e)),
+        };
+
+        match desired.diff(&lobby, &metadata) {
+            Some(transaction) => self.update_lobby(lobby_id, &transaction, |discord, result| {
+                callback(discord, result.map(|()| true))
+            }),
+            None => callback(self, Ok(false)),
+        }
+    }
+
     /// Deletes a given lobby.
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#deletelobby)
@@ -92,8 +137,13 @@ impl<'d, E> Discord<'d, E> {
         lobby_id: LobbyID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("lobbies", "delete_lobby", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.lobby_manager();
@@ -122,11 +172,17 @@ impl<'d, E> Discord<'d, E> {
             secret.to_mut().push('\0')
         }
 
+        let call = self.async_call_context("lobbies", "connect_lobby", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, lobby: *mut sys::DiscordLobby| {
                 callback(
                     discord,
-                    res.to_result().map(|()| unsafe { &*(lobby as *mut Lobby) }),
+                    res.to_result()
+                        .context(&call)
+                        .map(|()| unsafe { &*(lobby as *mut Lobby) }),
                 )
             },
         );
@@ -164,11 +220,16 @@ impl<'d, E> Discord<'d, E> {
             activity_secret.to_mut().push('\0')
         }
 
+        let call =
+            self.async_call_context("lobbies", "connect_lobby_with_activity_secret", Vec::new);
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, lobby: *mut sys::DiscordLobby| {
                 callback(
                     discord,
-                    res.to_result().map(|()| unsafe { &*(lobby as *mut Lobby) }),
+                    res.to_result()
+                        .context(&call)
+                        .map(|()| unsafe { &*(lobby as *mut Lobby) }),
                 )
             },
         );
@@ -194,8 +255,13 @@ impl<'d, E> Discord<'d, E> {
         lobby_id: LobbyID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("lobbies", "disconnect_lobby", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.lobby_manager();
@@ -211,10 +277,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby(&self, lobby_id: LobbyID) -> Result<Lobby> {
         let mut lobby = Lobby(sys::DiscordLobby::default());
 
+        let call = self.call_context("lobbies", "lobby", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).get_lobby.unwrap()(mgr, lobby_id, &mut lobby.0).to_result()?;
+            (*mgr).get_lobby.unwrap()(mgr, lobby_id, &mut lobby.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(lobby)
@@ -229,10 +301,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_activity_secret(&self, lobby_id: LobbyID) -> Result<String> {
         let mut secret: sys::DiscordLobbySecret = [0; size_of::<sys::DiscordLobbySecret>()];
 
+        let call = self.call_context("lobbies", "lobby_activity_secret", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).get_lobby_activity_secret.unwrap()(mgr, lobby_id, &mut secret).to_result()?;
+            (*mgr).get_lobby_activity_secret.unwrap()(mgr, lobby_id, &mut secret)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(utils::charbuf_to_str(&secret).to_string())
@@ -258,6 +336,13 @@ impl<'d, E> Discord<'d, E> {
             key.to_mut().push('\0')
         }
 
+        let call = self.call_context("lobbies", "lobby_metadata", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("key", key.trim_end_matches('\0').to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
@@ -268,7 +353,8 @@ impl<'d, E> Discord<'d, E> {
                 key.as_ptr() as *mut u8,
                 &mut value,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(utils::charbuf_to_str(&value).to_string())
@@ -280,10 +366,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_metadata_count(&self, lobby_id: LobbyID) -> Result<u32> {
         let mut count = 0;
 
+        let call = self.call_context("lobbies", "lobby_metadata_count", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).lobby_metadata_count.unwrap()(mgr, lobby_id, &mut count).to_result()?
+            (*mgr).lobby_metadata_count.unwrap()(mgr, lobby_id, &mut count)
+                .to_result()
+                .context(&call)?
         }
 
         // XXX: i32 should be u32
@@ -299,6 +391,13 @@ impl<'d, E> Discord<'d, E> {
         let mut key: sys::DiscordMetadataKey = [0; size_of::<sys::DiscordMetadataKey>()];
         let mut value: sys::DiscordMetadataValue = [0; size_of::<sys::DiscordMetadataValue>()];
 
+        let call = self.call_context("lobbies", "lobby_metadata_at", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("index", index.to_string()),
+            ]
+        });
+
         unsafe {
             (*mgr).get_lobby_metadata_key.unwrap()(
                 mgr,
@@ -307,7 +406,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut key,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
 
             (*mgr).get_lobby_metadata_value.unwrap()(
                 mgr,
@@ -316,7 +416,8 @@ impl<'d, E> Discord<'d, E> {
                 key.as_ptr() as *mut u8,
                 &mut value,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok((
@@ -353,6 +454,13 @@ impl<'d, E> Discord<'d, E> {
         transaction: &LobbyMemberTransaction,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
+        let call = self.async_call_context("lobbies", "update_member", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+            ]
+        });
+
         let mgr = unsafe { self.lobby_manager() };
         let mut tx = std::ptr::null_mut();
 
@@ -362,15 +470,16 @@ impl<'d, E> Discord<'d, E> {
         };
 
         if let Err(e) = create {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
         if let Err(e) = unsafe { transaction.process(tx) } {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe { (*mgr).update_member.unwrap()(mgr, lobby_id, user_id, tx, ptr, fun) }
     }
@@ -381,10 +490,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_member_count(&self, lobby_id: LobbyID) -> Result<u32> {
         let mut count = 0;
 
+        let call = self.call_context("lobbies", "lobby_member_count", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).member_count.unwrap()(mgr, lobby_id, &mut count).to_result()?;
+            (*mgr).member_count.unwrap()(mgr, lobby_id, &mut count)
+                .to_result()
+                .context(&call)?;
         }
 
         // XXX: i32 should be u32
@@ -397,6 +512,13 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_member_id_at(&self, lobby_id: LobbyID, index: u32) -> Result<UserID> {
         let mut user_id = 0;
 
+        let call = self.call_context("lobbies", "lobby_member_id_at", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("index", index.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
@@ -407,7 +529,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut user_id,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(user_id)
@@ -431,6 +554,73 @@ impl<'d, E> Discord<'d, E> {
         ))
     }
 
+    /// Returns the user of a lobby member, without fetching it.
+    ///
+    /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#getmemberuser)
+    pub fn lobby_member_user(&self, lobby_id: LobbyID, user_id: UserID) -> Result<User> {
+        let mut user = User(sys::DiscordUser::default());
+
+        let call = self.call_context("lobbies", "lobby_member_user", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+            ]
+        });
+
+        unsafe {
+            let mgr = self.lobby_manager();
+
+            (*mgr).get_member_user.unwrap()(mgr, lobby_id, user_id, &mut user.0)
+                .to_result()
+                .context(&call)?;
+        }
+
+        Ok(user)
+    }
+
+    /// Returns an `Iterator` over the members of a lobby, with their metadata.
+    ///
+    /// ```rust
+    /// # use discord_game_sdk::*;
+    /// # fn example(discord: Discord<'_, ()>, lobby_id: LobbyID) -> Result<()> {
+    /// for member in discord.iter_lobby_members(lobby_id)? {
+    ///     let (user, metadata) = member?;
+    ///
+    ///     println!(
+    ///         "{}#{} {}",
+    ///         user.username(),
+    ///         user.discriminator(),
+    ///         metadata.get("ready").map_or("", |_| "(ready)"),
+    ///     );
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_lobby_members(
+        &self,
+        lobby_id: LobbyID,
+    ) -> Result<
+        impl '_
+            + Iterator<Item = Result<(User, BTreeMap<String, String>)>>
+            + DoubleEndedIterator
+            + ExactSizeIterator
+            + std::iter::FusedIterator
+            + std::fmt::Debug,
+    > {
+        Ok(iter::Collection::new(
+            Box::new(move |i| {
+                let discord = self.ref_copy();
+                let user_id = discord.lobby_member_id_at(lobby_id, i)?;
+                let user = discord.lobby_member_user(lobby_id, user_id)?;
+                let metadata = discord
+                    .iter_lobby_member_metadata(lobby_id, user_id)?
+                    .collect::<Result<_>>()?;
+
+                Ok((user, metadata))
+            }),
+            self.lobby_member_count(lobby_id)?,
+        ))
+    }
+
     /// Returns member metadata value for a given key.
     ///
     /// ## Performance
@@ -452,6 +642,14 @@ impl<'d, E> Discord<'d, E> {
             key.to_mut().push('\0')
         }
 
+        let call = self.call_context("lobbies", "lobby_member_metadata", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+                ("key", key.trim_end_matches('\0').to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
@@ -463,7 +661,8 @@ impl<'d, E> Discord<'d, E> {
                 key.as_ptr() as *mut u8,
                 &mut value,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(utils::charbuf_to_str(&value).to_string())
@@ -475,11 +674,19 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_member_metadata_count(&self, lobby_id: LobbyID, user_id: UserID) -> Result<u32> {
         let mut count = 0;
 
+        let call = self.call_context("lobbies", "lobby_member_metadata_count", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
             (*mgr).member_metadata_count.unwrap()(mgr, lobby_id, user_id, &mut count)
-                .to_result()?;
+                .to_result()
+                .context(&call)?;
         }
 
         // XXX: i32 should be u32
@@ -500,6 +707,14 @@ impl<'d, E> Discord<'d, E> {
         let mut key: sys::DiscordMetadataKey = [0; size_of::<sys::DiscordMetadataKey>()];
         let mut value: sys::DiscordMetadataValue = [0; size_of::<sys::DiscordMetadataValue>()];
 
+        let call = self.call_context("lobbies", "lobby_member_metadata_at", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+                ("index", index.to_string()),
+            ]
+        });
+
         unsafe {
             (*mgr).get_member_metadata_key.unwrap()(
                 mgr,
@@ -509,7 +724,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut key,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         unsafe {
@@ -521,7 +737,8 @@ impl<'d, E> Discord<'d, E> {
                 key.as_ptr() as *mut u8,
                 &mut value,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok((
@@ -571,8 +788,13 @@ impl<'d, E> Discord<'d, E> {
 
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("lobbies", "send_lobby_message", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.lobby_manager();
@@ -602,21 +824,24 @@ impl<'d, E> Discord<'d, E> {
         search: &SearchQuery,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
+        let call = self.async_call_context("lobbies", "lobby_search", Vec::new);
+
         let mgr = unsafe { self.lobby_manager() };
         let mut tx = std::ptr::null_mut();
 
         let create = unsafe { (*mgr).get_search_query.unwrap()(mgr, &mut tx).to_result() };
 
         if let Err(e) = create {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
         if let Err(e) = unsafe { search.process(tx) } {
-            return callback(self, Err(e));
+            return callback(self, Err(e).context(&call));
         }
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe { (*mgr).search.unwrap()(mgr, tx, ptr, fun) }
     }
@@ -647,6 +872,10 @@ impl<'d, E> Discord<'d, E> {
     pub fn lobby_id_at(&self, index: u32) -> Result<LobbyID> {
         let mut lobby_id = 0;
 
+        let call = self.call_context("lobbies", "lobby_id_at", || {
+            vec![("index", index.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
@@ -656,7 +885,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut lobby_id,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(lobby_id)
@@ -690,8 +920,13 @@ impl<'d, E> Discord<'d, E> {
         lobby_id: LobbyID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("lobbies", "connect_lobby_voice", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.lobby_manager();
@@ -708,8 +943,13 @@ impl<'d, E> Discord<'d, E> {
         lobby_id: LobbyID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("lobbies", "disconnect_lobby_voice", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.lobby_manager();
@@ -724,10 +964,16 @@ impl<'d, E> Discord<'d, E> {
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#connectnetwork)
     pub fn connect_lobby_network(&self, lobby_id: LobbyID) -> Result<()> {
+        let call = self.call_context("lobbies", "connect_lobby_network", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).connect_network.unwrap()(mgr, lobby_id).to_result()
+            (*mgr).connect_network.unwrap()(mgr, lobby_id)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -735,10 +981,16 @@ impl<'d, E> Discord<'d, E> {
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#disconnectnetwork)
     pub fn disconnect_lobby_network(&self, lobby_id: LobbyID) -> Result<()> {
+        let call = self.call_context("lobbies", "disconnect_lobby_network", || {
+            vec![("lobby_id", lobby_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).disconnect_network.unwrap()(mgr, lobby_id).to_result()
+            (*mgr).disconnect_network.unwrap()(mgr, lobby_id)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -748,10 +1000,14 @@ impl<'d, E> Discord<'d, E> {
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#flushnetwork)
     pub fn flush_lobby_network(&self) -> Result<()> {
+        let call = self.call_context("lobbies", "flush_lobby_network", Vec::new);
+
         unsafe {
             let mgr = self.lobby_manager();
 
-            (*mgr).flush_network.unwrap()(mgr).to_result()
+            (*mgr).flush_network.unwrap()(mgr)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -764,11 +1020,20 @@ impl<'d, E> Discord<'d, E> {
         channel_id: NetworkChannelID,
         reliable: Reliability,
     ) -> Result<()> {
+        let call = self.call_context("lobbies", "open_lobby_network_channel", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+                ("reliable", format!("{:?}", reliable)),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
             (*mgr).open_network_channel.unwrap()(mgr, lobby_id, channel_id, reliable.into())
                 .to_result()
+                .context(&call)
         }
     }
 
@@ -784,6 +1049,14 @@ impl<'d, E> Discord<'d, E> {
     ) -> Result<()> {
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
+        let call = self.call_context("lobbies", "send_lobby_network_message", || {
+            vec![
+                ("lobby_id", lobby_id.to_string()),
+                ("user_id", user_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.lobby_manager();
 
@@ -798,6 +1071,7 @@ impl<'d, E> Discord<'d, E> {
                 buffer.len().try_into().unwrap_or(u32::max_value()),
             )
             .to_result()
+            .context(&call)
         }
     }
 }
@@ -0,0 +1,115 @@
+use crate::{
+    metadata, Discord, LobbyID, LobbyMemberTransaction, LobbyTransaction, MetadataError, Result,
+    UserID,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::BTreeMap;
+
+/// # Typed Metadata
+///
+/// Only available with the `serde` feature.
+///
+/// Structs and maps are stored as one metadata entry per field: strings as-is, numbers in
+/// decimal, booleans as `true` or `false`, and unit enum variants as their name.
+/// Fields that are `None` delete their entry, and missing entries are read back as `None`.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct LobbyInfo {
+///     mode: String,
+///     region: String,
+///     round: u32,
+///     password: Option<String>,
+/// }
+///
+/// # fn example(discord: Discord<'_, ()>, lobby_id: LobbyID) -> std::result::Result<(), MetadataError> {
+/// let info = LobbyInfo {
+///     mode: "ranked".to_string(),
+///     region: "eu".to_string(),
+///     round: 1,
+///     password: None,
+/// };
+///
+/// discord.update_lobby_typed(lobby_id, &info, move |discord, result| {
+///     if let Err(error) = result {
+///         return eprintln!("failed to update lobby: {}", error);
+///     }
+///
+///     match discord.lobby_metadata_typed::<LobbyInfo>(lobby_id) {
+///         Ok(info) => println!("round {} in {}", info.round, info.region),
+///         Err(error) => eprintln!("invalid lobby metadata: {}", error),
+///     }
+/// })?;
+/// # Ok(()) }
+/// ```
+impl<'d, E> Discord<'d, E> {
+    /// Writes `value` to the lobby metadata, see [`update_lobby`](#method.update_lobby).
+    ///
+    /// Other metadata entries are left unchanged.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Err` without updating the lobby if `value` can't be stored in metadata.
+    pub fn update_lobby_typed<T: Serialize>(
+        &self,
+        lobby_id: LobbyID,
+        value: &T,
+        callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
+    ) -> std::result::Result<(), MetadataError> {
+        let mut transaction = LobbyTransaction::new();
+        transaction.add_typed_metadata(value)?;
+
+        self.update_lobby(lobby_id, &transaction, callback);
+        Ok(())
+    }
+
+    /// Reads the lobby metadata into a `T`, entries that are not fields of `T` are ignored.
+    pub fn lobby_metadata_typed<T: DeserializeOwned>(
+        &self,
+        lobby_id: LobbyID,
+    ) -> std::result::Result<T, MetadataError> {
+        let metadata = self
+            .iter_lobby_metadata(lobby_id)?
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        metadata::from_metadata(&metadata)
+    }
+
+    /// Writes `value` to the metadata of a lobby member, see
+    /// [`update_member`](#method.update_member).
+    ///
+    /// Other metadata entries are left unchanged.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Err` without updating the member if `value` can't be stored in metadata.
+    pub fn update_member_typed<T: Serialize>(
+        &self,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        value: &T,
+        callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
+    ) -> std::result::Result<(), MetadataError> {
+        let mut transaction = LobbyMemberTransaction::new();
+        transaction.add_typed_metadata(value)?;
+
+        self.update_member(lobby_id, user_id, &transaction, callback);
+        Ok(())
+    }
+
+    /// Reads the metadata of a lobby member into a `T`, entries that are not fields of `T`
+    /// are ignored.
+    pub fn lobby_member_metadata_typed<T: DeserializeOwned>(
+        &self,
+        lobby_id: LobbyID,
+        user_id: UserID,
+    ) -> std::result::Result<T, MetadataError> {
+        let metadata = self
+            .iter_lobby_member_metadata(lobby_id, user_id)?
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        metadata::from_metadata(&metadata)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{to_result::ToResult, Discord, NetworkChannelID, NetworkPeerID, Reliability, Result};
+use crate::{
+    error_context::WithContext, to_result::ToResult, Discord, NetworkChannelID, NetworkPeerID,
+    Reliability, Result,
+};
 use std::{
     borrow::Cow,
     convert::{TryFrom, TryInto},
@@ -30,10 +33,12 @@ impl<E> Discord<'_, E> {
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/networking#flush)
     pub fn flush_network(&self) -> Result<()> {
+        let call = self.call_context("networking", "flush_network", Vec::new);
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).flush.unwrap()(mgr).to_result()
+            (*mgr).flush.unwrap()(mgr).to_result().context(&call)
         }
     }
 
@@ -55,10 +60,16 @@ impl<E> Discord<'_, E> {
             route.to_mut().push('\0')
         }
 
+        let call = self.call_context("networking", "open_peer", || {
+            vec![("peer_id", peer_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).open_peer.unwrap()(mgr, peer_id, route.as_ptr()).to_result()
+            (*mgr).open_peer.unwrap()(mgr, peer_id, route.as_ptr())
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -83,10 +94,16 @@ impl<E> Discord<'_, E> {
             route.to_mut().push('\0')
         }
 
+        let call = self.call_context("networking", "update_peer", || {
+            vec![("peer_id", peer_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).update_peer.unwrap()(mgr, peer_id, route.as_ptr()).to_result()
+            (*mgr).update_peer.unwrap()(mgr, peer_id, route.as_ptr())
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -94,10 +111,16 @@ impl<E> Discord<'_, E> {
     ///
     /// > [Method in official docs](https://discordapp.com/developers/docs/game-sdk/networking#closepeer)
     pub fn close_peer(&self, peer_id: NetworkPeerID) -> Result<()> {
+        let call = self.call_context("networking", "close_peer", || {
+            vec![("peer_id", peer_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).close_peer.unwrap()(mgr, peer_id).to_result()
+            (*mgr).close_peer.unwrap()(mgr, peer_id)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -110,10 +133,20 @@ impl<E> Discord<'_, E> {
         channel_id: NetworkChannelID,
         reliable: Reliability,
     ) -> Result<()> {
+        let call = self.call_context("networking", "open_channel", || {
+            vec![
+                ("peer_id", peer_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+                ("reliable", format!("{:?}", reliable)),
+            ]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).open_channel.unwrap()(mgr, peer_id, channel_id, reliable.into()).to_result()
+            (*mgr).open_channel.unwrap()(mgr, peer_id, channel_id, reliable.into())
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -125,10 +158,19 @@ impl<E> Discord<'_, E> {
         peer_id: NetworkPeerID,
         channel_id: NetworkChannelID,
     ) -> Result<()> {
+        let call = self.call_context("networking", "close_channel", || {
+            vec![
+                ("peer_id", peer_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
-            (*mgr).close_channel.unwrap()(mgr, peer_id, channel_id).to_result()
+            (*mgr).close_channel.unwrap()(mgr, peer_id, channel_id)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -145,6 +187,13 @@ impl<E> Discord<'_, E> {
 
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
+        let call = self.call_context("networking", "send_message", || {
+            vec![
+                ("peer_id", peer_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.network_manager();
 
@@ -158,6 +207,7 @@ impl<E> Discord<'_, E> {
                 buffer.len().try_into().unwrap_or(u32::max_value()),
             )
             .to_result()
+            .context(&call)
         }
     }
 }
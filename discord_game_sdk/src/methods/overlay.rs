@@ -1,4 +1,4 @@
-use crate::{sys, to_result::ToResult, Action, Discord, Result};
+use crate::{error_context::WithContext, sys, to_result::ToResult, Action, Discord, Result};
 use std::borrow::Cow;
 
 /// # Overlay
@@ -83,8 +83,13 @@ impl<'d, E> Discord<'d, E> {
         opened: bool,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("overlay", "set_overlay_opened", || {
+            vec![("opened", opened.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.overlay_manager();
@@ -113,8 +118,13 @@ impl<'d, E> Discord<'d, E> {
         action: Action,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("overlay", "open_invite_overlay", || {
+            vec![("action", format!("{:?}", action))]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.overlay_manager();
@@ -155,8 +165,13 @@ impl<'d, E> Discord<'d, E> {
             code.to_mut().push('\0')
         }
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("overlay", "open_guild_invite_overlay", || {
+            vec![("code", code.trim_end_matches('\0').to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.overlay_manager();
@@ -182,8 +197,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn open_voice_settings(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("overlay", "open_voice_settings", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.overlay_manager();
@@ -1,4 +1,7 @@
-use crate::{iter, sys, to_result::ToResult, utils, Discord, Relationship, Result, UserID};
+use crate::{
+    error_context::WithContext, iter, sys, to_result::ToResult, utils, Discord, Relationship,
+    Result, UserID,
+};
 use std::convert::TryInto;
 
 /// # Relationships
@@ -18,10 +21,16 @@ impl<E> Discord<'_, E> {
     pub fn relationship_with(&self, user_id: UserID) -> Result<Relationship> {
         let mut relationship = Relationship(sys::DiscordRelationship::default());
 
+        let call = self.call_context("relationships", "relationship_with", || {
+            vec![("user_id", user_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.relationship_manager();
 
-            (*mgr).get.unwrap()(mgr, user_id, &mut relationship.0).to_result()?;
+            (*mgr).get.unwrap()(mgr, user_id, &mut relationship.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(relationship)
@@ -78,10 +87,14 @@ impl<E> Discord<'_, E> {
     pub fn relationship_count(&self) -> Result<u32> {
         let mut count = 0;
 
+        let call = self.call_context("relationships", "relationship_count", Vec::new);
+
         unsafe {
             let mgr = self.relationship_manager();
 
-            (*mgr).count.unwrap()(mgr, &mut count).to_result()?;
+            (*mgr).count.unwrap()(mgr, &mut count)
+                .to_result()
+                .context(&call)?;
         }
 
         // XXX: i32 should be u32
@@ -99,10 +112,16 @@ impl<E> Discord<'_, E> {
     pub fn relationship_at(&self, index: u32) -> Result<Relationship> {
         let mut relationship = Relationship(sys::DiscordRelationship::default());
 
+        let call = self.call_context("relationships", "relationship_at", || {
+            vec![("index", index.to_string())]
+        });
+
         unsafe {
             let mgr = self.relationship_manager();
 
-            (*mgr).get_at.unwrap()(mgr, index, &mut relationship.0).to_result()?;
+            (*mgr).get_at.unwrap()(mgr, index, &mut relationship.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(relationship)
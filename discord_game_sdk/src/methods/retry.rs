@@ -0,0 +1,61 @@
+use crate::{discord::DeferredRetry, utils, Discord, Retry, RetryPolicy};
+use std::{rc::Rc, time::Instant};
+
+/// # Retries
+impl<'d, E> Discord<'d, E> {
+    /// Variants of the methods taking a `callback` that retry transient failures.
+    ///
+    /// See [`Retry`](struct.Retry.html).
+    pub fn retry(&self, policy: &RetryPolicy) -> Retry<'_, 'd, E> {
+        Retry {
+            discord: self,
+            policy: Rc::new(policy.clone()),
+        }
+    }
+
+    pub(crate) fn defer_retry(&self, at: Instant, retry: DeferredRetry<'d, E>) {
+        // SAFETY: Mutating through an immutable reference
+        // - `retries` is an `UnsafeCell`, inner mutation is legal
+        // - It is only otherwise accessed by `run_retries` and `abort_retries`,
+        //   which never hold a reference to it while calling a retry
+        unsafe { (*self.inner().retries.get()).push((at, retry)) }
+    }
+
+    // SAFETY: must not be called during callbacks
+    pub(crate) unsafe fn run_retries(&self, now: Instant) {
+        let due = {
+            let retries = &mut *self.inner().retries.get();
+            let mut due = Vec::new();
+            let mut i = 0;
+
+            while i < retries.len() {
+                if retries[i].0 <= now {
+                    due.push(retries.remove(i));
+                } else {
+                    i += 1;
+                }
+            }
+
+            due
+        };
+
+        for (_, retry) in due {
+            utils::guard_callback(self.0, || retry(self, true));
+        }
+    }
+
+    // Called before `core` is destroyed
+    pub(crate) unsafe fn abort_retries(&self) {
+        loop {
+            let retries: Vec<_> = (*self.inner().retries.get()).drain(..).collect();
+
+            if retries.is_empty() {
+                break;
+            }
+
+            for (_, retry) in retries {
+                utils::guard_callback(self.0, || retry(self, false));
+            }
+        }
+    }
+}
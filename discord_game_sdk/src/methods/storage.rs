@@ -1,4 +1,6 @@
-use crate::{iter, sys, to_result::ToResult, utils, Discord, FileStat, Result};
+use crate::{
+    error_context::WithContext, iter, sys, to_result::ToResult, utils, Discord, FileStat, Result,
+};
 use std::{
     borrow::Cow,
     convert::{TryFrom, TryInto},
@@ -48,6 +50,10 @@ impl<'d, E> Discord<'d, E> {
 
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
+        let call = self.call_context("storage", "read_file", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
@@ -59,7 +65,8 @@ impl<'d, E> Discord<'d, E> {
                 buffer.len().try_into().unwrap_or(u32::max_value()),
                 &mut read,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         // XXX: u32 should be u64
@@ -96,11 +103,16 @@ impl<'d, E> Discord<'d, E> {
             filename.to_mut().push('\0')
         }
 
+        let call = self.async_call_context("storage", "read_file_async", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         let (ptr, fun) = self.three_params(
             move |discord, res: sys::EDiscordResult, data: *mut u8, data_len: u32| {
                 callback(
                     discord,
                     res.to_result()
+                        .context(&call)
                         .map(|()| unsafe { std::slice::from_raw_parts(data, data_len as usize) }),
                 )
             },
@@ -146,11 +158,20 @@ impl<'d, E> Discord<'d, E> {
             filename.to_mut().push('\0')
         }
 
+        let call = self.async_call_context("storage", "read_file_async_partial", || {
+            vec![
+                ("filename", filename.trim_end_matches('\0').to_string()),
+                ("offset", offset.to_string()),
+                ("length", length.to_string()),
+            ]
+        });
+
         let (ptr, fun) = self.three_params(
             move |discord, res: sys::EDiscordResult, data: *mut u8, data_len: u32| {
                 callback(
                     discord,
                     res.to_result()
+                        .context(&call)
                         .map(|()| unsafe { std::slice::from_raw_parts(data, data_len as usize) }),
                 )
             },
@@ -196,6 +217,10 @@ impl<'d, E> Discord<'d, E> {
 
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
+        let call = self.call_context("storage", "write_file", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
@@ -208,6 +233,7 @@ impl<'d, E> Discord<'d, E> {
                 buffer.len().try_into().unwrap_or(u32::max_value()),
             )
             .to_result()
+            .context(&call)
         }
     }
 
@@ -249,8 +275,13 @@ impl<'d, E> Discord<'d, E> {
 
         debug_assert!(u32::try_from(buffer.len()).is_ok());
 
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("storage", "write_file_async", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.storage_manager();
@@ -289,10 +320,16 @@ impl<'d, E> Discord<'d, E> {
             filename.to_mut().push('\0')
         }
 
+        let call = self.call_context("storage", "delete_file", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
-            (*mgr).delete_.unwrap()(mgr, filename.as_ptr()).to_result()
+            (*mgr).delete_.unwrap()(mgr, filename.as_ptr())
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -321,10 +358,16 @@ impl<'d, E> Discord<'d, E> {
 
         let mut exists = false;
 
+        let call = self.call_context("storage", "file_exists", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
-            (*mgr).exists.unwrap()(mgr, filename.as_ptr(), &mut exists).to_result()?;
+            (*mgr).exists.unwrap()(mgr, filename.as_ptr(), &mut exists)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(exists)
@@ -353,10 +396,16 @@ impl<'d, E> Discord<'d, E> {
 
         let mut stat = FileStat(sys::DiscordFileStat::default());
 
+        let call = self.call_context("storage", "file_stat", || {
+            vec![("filename", filename.trim_end_matches('\0').to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
-            (*mgr).stat.unwrap()(mgr, filename.as_ptr(), &mut stat.0).to_result()?;
+            (*mgr).stat.unwrap()(mgr, filename.as_ptr(), &mut stat.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(stat)
@@ -388,6 +437,10 @@ impl<'d, E> Discord<'d, E> {
     pub fn file_stat_at(&self, index: u32) -> Result<FileStat> {
         let mut stat = FileStat(sys::DiscordFileStat::default());
 
+        let call = self.call_context("storage", "file_stat_at", || {
+            vec![("index", index.to_string())]
+        });
+
         unsafe {
             let mgr = self.storage_manager();
 
@@ -397,7 +450,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut stat.0,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(stat)
@@ -442,10 +496,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn folder_path(&self) -> Result<String> {
         let mut path: sys::DiscordPath = [0; size_of::<sys::DiscordPath>()];
 
+        let call = self.call_context("storage", "folder_path", Vec::new);
+
         unsafe {
             let mgr = self.storage_manager();
 
-            (*mgr).get_path.unwrap()(mgr, &mut path).to_result()?;
+            (*mgr).get_path.unwrap()(mgr, &mut path)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(utils::charbuf_to_str(&path).to_string())
@@ -1,4 +1,7 @@
-use crate::{iter, sys, to_result::ToResult, Discord, Entitlement, Result, Sku, Snowflake};
+use crate::{
+    error_context::WithContext, iter, sys, to_result::ToResult, Discord, Entitlement, Result, Sku,
+    Snowflake,
+};
 use std::convert::TryInto;
 
 /// # Store
@@ -30,8 +33,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn fetch_skus(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("store", "fetch_skus", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.store_manager();
@@ -67,10 +73,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn sku(&self, id: Snowflake) -> Result<Sku> {
         let mut sku = Sku(sys::DiscordSku::default());
 
+        let call = self.call_context("store", "sku", || vec![("id", id.to_string())]);
+
         unsafe {
             let mgr = self.store_manager();
 
-            (*mgr).get_sku.unwrap()(mgr, id, &mut sku.0).to_result()?;
+            (*mgr).get_sku.unwrap()(mgr, id, &mut sku.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(sku)
@@ -106,6 +116,8 @@ impl<'d, E> Discord<'d, E> {
     pub fn sku_at(&self, index: u32) -> Result<Sku> {
         let mut sku = Sku(sys::DiscordSku::default());
 
+        let call = self.call_context("store", "sku_at", || vec![("index", index.to_string())]);
+
         unsafe {
             let mgr = self.store_manager();
 
@@ -115,7 +127,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut sku.0,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(sku)
@@ -175,8 +188,11 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn fetch_entitlements(&self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("store", "fetch_entitlements", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.store_manager();
@@ -212,10 +228,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn entitlement(&self, id: Snowflake) -> Result<Entitlement> {
         let mut entitlement = Entitlement(sys::DiscordEntitlement::default());
 
+        let call = self.call_context("store", "entitlement", || vec![("id", id.to_string())]);
+
         unsafe {
             let mgr = self.store_manager();
 
-            (*mgr).get_entitlement.unwrap()(mgr, id, &mut entitlement.0).to_result()?;
+            (*mgr).get_entitlement.unwrap()(mgr, id, &mut entitlement.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(entitlement)
@@ -251,6 +271,10 @@ impl<'d, E> Discord<'d, E> {
     pub fn entitlement_at(&self, index: u32) -> Result<Entitlement> {
         let mut entitlement = Entitlement(sys::DiscordEntitlement::default());
 
+        let call = self.call_context("store", "entitlement_at", || {
+            vec![("index", index.to_string())]
+        });
+
         unsafe {
             let mgr = self.store_manager();
 
@@ -260,7 +284,8 @@ impl<'d, E> Discord<'d, E> {
                 index.try_into().unwrap(),
                 &mut entitlement.0,
             )
-            .to_result()?;
+            .to_result()
+            .context(&call)?;
         }
 
         Ok(entitlement)
@@ -316,10 +341,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn has_entitlement(&self, sku_id: Snowflake) -> Result<bool> {
         let mut has_entitlement = false;
 
+        let call = self.call_context("store", "has_entitlement", || {
+            vec![("sku_id", sku_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.store_manager();
 
-            (*mgr).has_sku_entitlement.unwrap()(mgr, sku_id, &mut has_entitlement).to_result()?;
+            (*mgr).has_sku_entitlement.unwrap()(mgr, sku_id, &mut has_entitlement)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(has_entitlement)
@@ -347,8 +378,13 @@ impl<'d, E> Discord<'d, E> {
         sku_id: Snowflake,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("store", "start_purchase", || {
+            vec![("sku_id", sku_id.to_string())]
+        });
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.store_manager();
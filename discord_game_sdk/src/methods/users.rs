@@ -1,4 +1,7 @@
-use crate::{sys, to_result::ToResult, Discord, PremiumKind, Result, User, UserFlags, UserID};
+use crate::{
+    error_context::WithContext, sys, to_result::ToResult, Discord, PremiumKind, Result, User,
+    UserFlags, UserID,
+};
 
 /// # Users
 ///
@@ -25,10 +28,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn current_user(&self) -> Result<User> {
         let mut user = User(sys::DiscordUser::default());
 
+        let call = self.call_context("users", "current_user", Vec::new);
+
         unsafe {
             let mgr = self.user_manager();
 
-            (*mgr).get_current_user.unwrap()(mgr, &mut user.0).to_result()?;
+            (*mgr).get_current_user.unwrap()(mgr, &mut user.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(user)
@@ -57,11 +64,16 @@ impl<'d, E> Discord<'d, E> {
         user_id: UserID,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&User>),
     ) {
+        let call =
+            self.async_call_context("users", "user", || vec![("user_id", user_id.to_string())]);
+
         let (ptr, fun) = self.two_params(
             move |discord, res: sys::EDiscordResult, user: *mut sys::DiscordUser| {
                 callback(
                     discord,
-                    res.to_result().map(|()| unsafe { &*(user as *mut User) }),
+                    res.to_result()
+                        .context(&call)
+                        .map(|()| unsafe { &*(user as *mut User) }),
                 )
             },
         );
@@ -86,10 +98,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn current_user_premium_kind(&self) -> Result<PremiumKind> {
         let mut premium_type = sys::EDiscordPremiumType::default();
 
+        let call = self.call_context("users", "current_user_premium_kind", Vec::new);
+
         unsafe {
             let mgr = self.user_manager();
 
-            (*mgr).get_current_user_premium_type.unwrap()(mgr, &mut premium_type).to_result()?;
+            (*mgr).get_current_user_premium_type.unwrap()(mgr, &mut premium_type)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(PremiumKind::from(premium_type))
@@ -108,6 +124,8 @@ impl<'d, E> Discord<'d, E> {
     pub fn current_user_flags(&self) -> Result<UserFlags> {
         let mut flags = UserFlags::empty();
 
+        let call = self.call_context("users", "current_user_flags", Vec::new);
+
         for flag in &[
             UserFlags::PARTNER,
             UserFlags::HYPE_SQUAD_EVENTS,
@@ -121,7 +139,8 @@ impl<'d, E> Discord<'d, E> {
                 let mgr = self.user_manager();
 
                 (*mgr).current_user_has_flag.unwrap()(mgr, flag.bits(), &mut contains)
-                    .to_result()?;
+                    .to_result()
+                    .context(&call)?;
             }
 
             flags.set(*flag, contains);
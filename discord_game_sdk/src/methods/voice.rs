@@ -1,4 +1,6 @@
-use crate::{sys, to_result::ToResult, Discord, InputMode, Result, UserID};
+use crate::{
+    error_context::WithContext, sys, to_result::ToResult, Discord, InputMode, Result, UserID,
+};
 
 /// # Voice
 ///
@@ -17,10 +19,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn input_mode(&self) -> Result<InputMode> {
         let mut input_mode = InputMode(sys::DiscordInputMode::default());
 
+        let call = self.call_context("voice", "input_mode", Vec::new);
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).get_input_mode.unwrap()(mgr, &mut input_mode.0).to_result()?;
+            (*mgr).get_input_mode.unwrap()(mgr, &mut input_mode.0)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(input_mode)
@@ -48,8 +54,11 @@ impl<'d, E> Discord<'d, E> {
         input_mode: InputMode,
         callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
     ) {
-        let (ptr, fun) = self
-            .one_param(move |discord, res: sys::EDiscordResult| callback(discord, res.to_result()));
+        let call = self.async_call_context("voice", "set_input_mode", Vec::new);
+
+        let (ptr, fun) = self.one_param(move |discord, res: sys::EDiscordResult| {
+            callback(discord, res.to_result().context(&call))
+        });
 
         unsafe {
             let mgr = self.voice_manager();
@@ -73,10 +82,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn self_muted(&self) -> Result<bool> {
         let mut muted = false;
 
+        let call = self.call_context("voice", "self_muted", Vec::new);
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).is_self_mute.unwrap()(mgr, &mut muted).to_result()?;
+            (*mgr).is_self_mute.unwrap()(mgr, &mut muted)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(muted)
@@ -97,10 +110,14 @@ impl<'d, E> Discord<'d, E> {
     pub fn self_deafened(&self) -> Result<bool> {
         let mut deafened = false;
 
+        let call = self.call_context("voice", "self_deafened", Vec::new);
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).is_self_deaf.unwrap()(mgr, &mut deafened).to_result()?;
+            (*mgr).is_self_deaf.unwrap()(mgr, &mut deafened)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(deafened)
@@ -117,10 +134,16 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn set_self_mute(&self, muted: bool) -> Result<()> {
+        let call = self.call_context("voice", "set_self_mute", || {
+            vec![("muted", muted.to_string())]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).set_self_mute.unwrap()(mgr, muted).to_result()
+            (*mgr).set_self_mute.unwrap()(mgr, muted)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -135,10 +158,16 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn set_self_deaf(&self, deafened: bool) -> Result<()> {
+        let call = self.call_context("voice", "set_self_deaf", || {
+            vec![("deafened", deafened.to_string())]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).set_self_deaf.unwrap()(mgr, deafened).to_result()
+            (*mgr).set_self_deaf.unwrap()(mgr, deafened)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -157,10 +186,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn local_muted(&self, user_id: UserID) -> Result<bool> {
         let mut muted = false;
 
+        let call = self.call_context("voice", "local_muted", || {
+            vec![("user_id", user_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).is_local_mute.unwrap()(mgr, user_id, &mut muted).to_result()?;
+            (*mgr).is_local_mute.unwrap()(mgr, user_id, &mut muted)
+                .to_result()
+                .context(&call)?;
         }
 
         Ok(muted)
@@ -179,10 +214,16 @@ impl<'d, E> Discord<'d, E> {
     pub fn local_volume(&self, user_id: UserID) -> Result<u8> {
         let mut volume = 0;
 
+        let call = self.call_context("voice", "local_volume", || {
+            vec![("user_id", user_id.to_string())]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).get_local_volume.unwrap()(mgr, user_id, &mut volume).to_result()?;
+            (*mgr).get_local_volume.unwrap()(mgr, user_id, &mut volume)
+                .to_result()
+                .context(&call)?;
         }
 
         debug_assert!((0..=200).contains(&volume));
@@ -201,10 +242,19 @@ impl<'d, E> Discord<'d, E> {
     /// # Ok(()) }
     /// ```
     pub fn set_local_mute(&self, user_id: UserID, muted: bool) -> Result<()> {
+        let call = self.call_context("voice", "set_local_mute", || {
+            vec![
+                ("user_id", user_id.to_string()),
+                ("muted", muted.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).set_local_mute.unwrap()(mgr, user_id, muted).to_result()
+            (*mgr).set_local_mute.unwrap()(mgr, user_id, muted)
+                .to_result()
+                .context(&call)
         }
     }
 
@@ -223,10 +273,19 @@ impl<'d, E> Discord<'d, E> {
     pub fn set_local_volume(&self, user_id: UserID, volume: u8) -> Result<()> {
         debug_assert!((0..=200).contains(&volume));
 
+        let call = self.call_context("voice", "set_local_volume", || {
+            vec![
+                ("user_id", user_id.to_string()),
+                ("volume", volume.to_string()),
+            ]
+        });
+
         unsafe {
             let mgr = self.voice_manager();
 
-            (*mgr).set_local_volume.unwrap()(mgr, user_id, volume).to_result()
+            (*mgr).set_local_volume.unwrap()(mgr, user_id, volume)
+                .to_result()
+                .context(&call)
         }
     }
 }
@@ -1,171 +1,2970 @@
-use crate::sys;
-use std::ffi::c_void;
+use super::{
+    state::{self, File, Lobby, Member},
+    MockBackend,
+};
+use crate::{sys, utils, LobbyID, UserID};
+use std::{cmp::Ordering, ffi::c_void};
+
+type Callback = Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult)>;
+
+// Every object handed to the SDK user starts with its vtable,
+// pointers to these objects are cast back and forth with pointers to the vtable
+
+#[repr(C)]
+struct Interface<V> {
+    vtable: V,
+    backend: MockBackend,
+}
+
+#[repr(C)]
+struct Core {
+    vtable: sys::IDiscordCore,
+    backend: MockBackend,
+
+    application: Interface<sys::IDiscordApplicationManager>,
+    user: Interface<sys::IDiscordUserManager>,
+    image: Interface<sys::IDiscordImageManager>,
+    activity: Interface<sys::IDiscordActivityManager>,
+    relationship: Interface<sys::IDiscordRelationshipManager>,
+    lobby: Interface<sys::IDiscordLobbyManager>,
+    network: Interface<sys::IDiscordNetworkManager>,
+    overlay: Interface<sys::IDiscordOverlayManager>,
+    storage: Interface<sys::IDiscordStorageManager>,
+    store: Interface<sys::IDiscordStoreManager>,
+    voice: Interface<sys::IDiscordVoiceManager>,
+    achievement: Interface<sys::IDiscordAchievementManager>,
+}
+
+#[repr(C)]
+pub(crate) struct LobbyTransaction {
+    vtable: sys::IDiscordLobbyTransaction,
+    kind: Option<sys::EDiscordLobbyType>,
+    owner_id: Option<UserID>,
+    capacity: Option<u32>,
+    locked: Option<bool>,
+    metadata: Vec<(String, Option<String>)>,
+}
+
+#[repr(C)]
+pub(crate) struct LobbyMemberTransaction {
+    vtable: sys::IDiscordLobbyMemberTransaction,
+    metadata: Vec<(String, Option<String>)>,
+}
+
+#[repr(C)]
+pub(crate) struct SearchQuery {
+    vtable: sys::IDiscordLobbySearchQuery,
+    filters: Vec<(
+        String,
+        sys::EDiscordLobbySearchComparison,
+        sys::EDiscordLobbySearchCast,
+        String,
+    )>,
+    sorts: Vec<(String, sys::EDiscordLobbySearchCast, String)>,
+    limit: Option<u32>,
+}
+
+impl<V> Interface<V> {
+    fn new(vtable: V, backend: &MockBackend) -> Self {
+        Self {
+            vtable,
+            backend: backend.clone(),
+        }
+    }
+}
+
+unsafe fn backend<V>(interface: *mut V) -> MockBackend {
+    let backend = (*(interface as *mut Interface<V>)).backend.clone();
+
+    // Only catches the use of an instance after `destroy` while its memory wasn't reused
+    assert!(
+        backend.state().params.is_some(),
+        "mock instance used after destroy"
+    );
+
+    backend
+}
+
+unsafe fn str_arg(ptr: *const u8) -> String {
+    utils::charptr_to_str(ptr).to_string()
+}
+
+// Most methods will queue the operation and resolve it with a `sys::EDiscordResult`
+fn respond(
+    backend: &MockBackend,
+    callback_data: *mut c_void,
+    callback: Callback,
+    operation: impl 'static + FnOnce(&MockBackend) -> sys::EDiscordResult,
+) {
+    backend.defer(move |backend, run| {
+        let result = if run {
+            operation(backend)
+        } else {
+            sys::DiscordResult_TransactionAborted
+        };
+
+        unsafe { callback.unwrap()(callback_data, result) }
+    })
+}
+
+pub(crate) unsafe fn create(
+    backend: &MockBackend,
+    version: sys::DiscordVersion,
+    params: *mut sys::DiscordCreateParams,
+    result: *mut *mut sys::IDiscordCore,
+) -> sys::EDiscordResult {
+    {
+        let mut state = backend.state();
+
+        if version != state.sdk_version {
+            return sys::DiscordResult_InvalidVersion;
+        }
+
+        if state.params.is_some() {
+            log::error!("mock backend is already in use by another instance");
+            return sys::DiscordResult_InternalError;
+        }
+
+        // Like the SDK, fail to connect while the client is closed
+        if !state.running {
+            return sys::DiscordResult_NotRunning;
+        }
+
+        state.params = Some(*params);
+    }
+
+    *result = Box::into_raw(Box::new(Core {
+        vtable: CORE,
+        backend: backend.clone(),
+
+        application: Interface::new(APPLICATION_MANAGER, backend),
+        user: Interface::new(USER_MANAGER, backend),
+        image: Interface::new(IMAGE_MANAGER, backend),
+        activity: Interface::new(ACTIVITY_MANAGER, backend),
+        relationship: Interface::new(RELATIONSHIP_MANAGER, backend),
+        lobby: Interface::new(LOBBY_MANAGER, backend),
+        network: Interface::new(NETWORK_MANAGER, backend),
+        overlay: Interface::new(OVERLAY_MANAGER, backend),
+        storage: Interface::new(STORAGE_MANAGER, backend),
+        store: Interface::new(STORE_MANAGER, backend),
+        voice: Interface::new(VOICE_MANAGER, backend),
+        achievement: Interface::new(ACHIEVEMENT_MANAGER, backend),
+    })) as *mut sys::IDiscordCore;
+
+    // The SDK fetches the current user and relationships when connecting
+    backend.defer(|backend, run| {
+        if run {
+            backend.fire(|params| {
+                (*params.user_events).on_current_user_update.unwrap()(params.event_data)
+            });
+            backend.fire(|params| {
+                (*params.relationship_events).on_refresh.unwrap()(params.event_data)
+            });
+        }
+    });
+
+    sys::DiscordResult_Ok
+}
+
+// Core
+
+const CORE: sys::IDiscordCore = sys::IDiscordCore {
+    destroy: Some(destroy),
+    run_callbacks: Some(run_callbacks),
+    set_log_hook: Some(set_log_hook),
+    get_application_manager: Some(get_application_manager),
+    get_user_manager: Some(get_user_manager),
+    get_image_manager: Some(get_image_manager),
+    get_activity_manager: Some(get_activity_manager),
+    get_relationship_manager: Some(get_relationship_manager),
+    get_lobby_manager: Some(get_lobby_manager),
+    get_network_manager: Some(get_network_manager),
+    get_overlay_manager: Some(get_overlay_manager),
+    get_storage_manager: Some(get_storage_manager),
+    get_store_manager: Some(get_store_manager),
+    get_voice_manager: Some(get_voice_manager),
+    get_achievement_manager: Some(get_achievement_manager),
+};
+
+unsafe extern "C" fn destroy(core: *mut sys::IDiscordCore) {
+    let backend = (*(core as *mut Core)).backend.clone();
+
+    // Pending operations are aborted, their callbacks may still use the instance
+    while let Some(task) = backend.pop_task() {
+        task(&backend, false);
+    }
+
+    {
+        let mut state = backend.state();
+        state.params = None;
+        state.free_transactions();
+    }
+
+    drop(Box::from_raw(core as *mut Core));
+}
+
+unsafe extern "C" fn run_callbacks(core: *mut sys::IDiscordCore) -> sys::EDiscordResult {
+    let backend = (*(core as *mut Core)).backend.clone();
+
+    if !backend.state().running {
+        return sys::DiscordResult_NotRunning;
+    }
+
+    // Operations queued while running callbacks are resolved on the next call
+    let pending = backend.state().queue.len();
+
+    for _ in 0..pending {
+        match backend.pop_task() {
+            Some(task) => task(&backend, true),
+            None => break,
+        }
+    }
+
+    sys::DiscordResult_Ok
+}
+
+unsafe extern "C" fn set_log_hook(
+    _: *mut sys::IDiscordCore,
+    _: sys::EDiscordLogLevel,
+    _: *mut c_void,
+    _: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordLogLevel, *const u8)>,
+) {
+}
+
+macro_rules! get_manager {
+    ($name:ident, $field:ident, $ty:ty) => {
+        unsafe extern "C" fn $name(core: *mut sys::IDiscordCore) -> *mut $ty {
+            &mut (*(core as *mut Core)).$field as *mut Interface<$ty> as *mut $ty
+        }
+    };
+}
+
+get_manager!(
+    get_application_manager,
+    application,
+    sys::IDiscordApplicationManager
+);
+get_manager!(get_user_manager, user, sys::IDiscordUserManager);
+get_manager!(get_image_manager, image, sys::IDiscordImageManager);
+get_manager!(get_activity_manager, activity, sys::IDiscordActivityManager);
+get_manager!(
+    get_relationship_manager,
+    relationship,
+    sys::IDiscordRelationshipManager
+);
+get_manager!(get_lobby_manager, lobby, sys::IDiscordLobbyManager);
+get_manager!(get_network_manager, network, sys::IDiscordNetworkManager);
+get_manager!(get_overlay_manager, overlay, sys::IDiscordOverlayManager);
+get_manager!(get_storage_manager, storage, sys::IDiscordStorageManager);
+get_manager!(get_store_manager, store, sys::IDiscordStoreManager);
+get_manager!(get_voice_manager, voice, sys::IDiscordVoiceManager);
+get_manager!(
+    get_achievement_manager,
+    achievement,
+    sys::IDiscordAchievementManager
+);
+
+// Applications
+
+const APPLICATION_MANAGER: sys::IDiscordApplicationManager = sys::IDiscordApplicationManager {
+    validate_or_exit: {
+        unsafe extern "C" fn validate_or_exit(
+            mgr: *mut sys::IDiscordApplicationManager,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, |_| {
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(validate_or_exit)
+    },
+
+    get_current_locale: {
+        unsafe extern "C" fn get_current_locale(
+            mgr: *mut sys::IDiscordApplicationManager,
+            locale: *mut sys::DiscordLocale,
+        ) {
+            utils::write_charbuf(&mut *locale, &backend(mgr).state().locale);
+        }
+
+        Some(get_current_locale)
+    },
+
+    get_current_branch: {
+        unsafe extern "C" fn get_current_branch(
+            mgr: *mut sys::IDiscordApplicationManager,
+            branch: *mut sys::DiscordBranch,
+        ) {
+            utils::write_charbuf(&mut *branch, &backend(mgr).state().branch);
+        }
+
+        Some(get_current_branch)
+    },
+
+    get_oauth2_token: {
+        unsafe extern "C" fn get_oauth2_token(
+            mgr: *mut sys::IDiscordApplicationManager,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(
+                    *mut c_void,
+                    sys::EDiscordResult,
+                    *mut sys::DiscordOAuth2Token,
+                ),
+            >,
+        ) {
+            backend(mgr).defer(move |backend, run| {
+                let mut token = backend.state().oauth2_token;
+
+                let result = if run {
+                    sys::DiscordResult_Ok
+                } else {
+                    sys::DiscordResult_TransactionAborted
+                };
+
+                callback.unwrap()(callback_data, result, &mut token)
+            })
+        }
+
+        Some(get_oauth2_token)
+    },
+
+    get_ticket: {
+        unsafe extern "C" fn get_ticket(
+            mgr: *mut sys::IDiscordApplicationManager,
+            callback_data: *mut c_void,
+            callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *const u8)>,
+        ) {
+            backend(mgr).defer(move |backend, run| {
+                let mut ticket = backend.state().app_ticket.clone();
+                ticket.push('\0');
+
+                let result = if run {
+                    sys::DiscordResult_Ok
+                } else {
+                    sys::DiscordResult_TransactionAborted
+                };
+
+                callback.unwrap()(callback_data, result, ticket.as_ptr())
+            })
+        }
+
+        Some(get_ticket)
+    },
+};
+
+// Users
+
+const USER_MANAGER: sys::IDiscordUserManager = sys::IDiscordUserManager {
+    get_current_user: {
+        unsafe extern "C" fn get_current_user(
+            mgr: *mut sys::IDiscordUserManager,
+            current_user: *mut sys::DiscordUser,
+        ) -> sys::EDiscordResult {
+            *current_user = backend(mgr).state().current_user;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_current_user)
+    },
+
+    get_user: {
+        unsafe extern "C" fn get_user(
+            mgr: *mut sys::IDiscordUserManager,
+            user_id: sys::DiscordUserId,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut sys::DiscordUser),
+            >,
+        ) {
+            backend(mgr).defer(move |backend, run| {
+                let user = backend.state().user(user_id);
+
+                let (result, mut user) = match (run, user) {
+                    (false, _) => (sys::DiscordResult_TransactionAborted, Default::default()),
+                    (true, None) => (sys::DiscordResult_NotFound, Default::default()),
+                    (true, Some(user)) => (sys::DiscordResult_Ok, user),
+                };
+
+                callback.unwrap()(callback_data, result, &mut user)
+            })
+        }
+
+        Some(get_user)
+    },
+
+    get_current_user_premium_type: {
+        unsafe extern "C" fn get_current_user_premium_type(
+            mgr: *mut sys::IDiscordUserManager,
+            premium_type: *mut sys::EDiscordPremiumType,
+        ) -> sys::EDiscordResult {
+            *premium_type = backend(mgr).state().premium_kind;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_current_user_premium_type)
+    },
+
+    current_user_has_flag: {
+        unsafe extern "C" fn current_user_has_flag(
+            mgr: *mut sys::IDiscordUserManager,
+            flag: sys::EDiscordUserFlag,
+            has_flag: *mut bool,
+        ) -> sys::EDiscordResult {
+            *has_flag = backend(mgr).state().user_flags & flag == flag;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(current_user_has_flag)
+    },
+};
+
+// Images
+
+const IMAGE_MANAGER: sys::IDiscordImageManager = sys::IDiscordImageManager {
+    fetch: {
+        unsafe extern "C" fn fetch(
+            mgr: *mut sys::IDiscordImageManager,
+            handle: sys::DiscordImageHandle,
+            _refresh: bool,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, sys::DiscordImageHandle),
+            >,
+        ) {
+            backend(mgr).defer(move |_, run| {
+                let result = if run {
+                    sys::DiscordResult_Ok
+                } else {
+                    sys::DiscordResult_TransactionAborted
+                };
+
+                callback.unwrap()(callback_data, result, handle)
+            })
+        }
+
+        Some(fetch)
+    },
+
+    get_dimensions: {
+        unsafe extern "C" fn get_dimensions(
+            mgr: *mut sys::IDiscordImageManager,
+            handle: sys::DiscordImageHandle,
+            dimensions: *mut sys::DiscordImageDimensions,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            *dimensions = match state.images.iter().find(|image| image.handle == handle) {
+                Some(image) => sys::DiscordImageDimensions {
+                    width: image.width,
+                    height: image.height,
+                },
+                // Avatars are square
+                None => sys::DiscordImageDimensions {
+                    width: handle.size,
+                    height: handle.size,
+                },
+            };
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_dimensions)
+    },
+
+    get_data: {
+        unsafe extern "C" fn get_data(
+            mgr: *mut sys::IDiscordImageManager,
+            handle: sys::DiscordImageHandle,
+            data: *mut u8,
+            data_length: u32,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            let data = std::slice::from_raw_parts_mut(data, data_length as usize);
+
+            match state.images.iter().find(|image| image.handle == handle) {
+                Some(image) if image.data.len() > data.len() => {
+                    return sys::DiscordResult_InsufficientBuffer
+                }
+                Some(image) => data[..image.data.len()].copy_from_slice(&image.data),
+                // Blank avatar
+                None => {
+                    for byte in data.iter_mut() {
+                        *byte = 0xFF
+                    }
+                }
+            }
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_data)
+    },
+};
+
+// Activities
+
+const ACTIVITY_MANAGER: sys::IDiscordActivityManager = sys::IDiscordActivityManager {
+    register_command: {
+        unsafe extern "C" fn register_command(
+            mgr: *mut sys::IDiscordActivityManager,
+            command: *const u8,
+        ) -> sys::EDiscordResult {
+            backend(mgr).state().launch_command = Some(str_arg(command));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(register_command)
+    },
+
+    register_steam: {
+        unsafe extern "C" fn register_steam(
+            mgr: *mut sys::IDiscordActivityManager,
+            steam_id: u32,
+        ) -> sys::EDiscordResult {
+            backend(mgr).state().steam_id = Some(steam_id);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(register_steam)
+    },
+
+    update_activity: {
+        unsafe extern "C" fn update_activity(
+            mgr: *mut sys::IDiscordActivityManager,
+            activity: *mut sys::DiscordActivity,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let activity = *activity;
+
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                backend.state().activity = Some(activity);
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(update_activity)
+    },
+
+    clear_activity: {
+        unsafe extern "C" fn clear_activity(
+            mgr: *mut sys::IDiscordActivityManager,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, |backend| {
+                backend.state().activity = None;
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(clear_activity)
+    },
+
+    send_request_reply: {
+        unsafe extern "C" fn send_request_reply(
+            mgr: *mut sys::IDiscordActivityManager,
+            user_id: sys::DiscordUserId,
+            reply: sys::EDiscordActivityJoinRequestReply,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut state = backend.state();
+
+                if state.user(user_id).is_none() {
+                    return sys::DiscordResult_NotFound;
+                }
+
+                state.sent_request_replies.push((user_id, reply.into()));
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(send_request_reply)
+    },
+
+    send_invite: {
+        unsafe extern "C" fn send_invite(
+            mgr: *mut sys::IDiscordActivityManager,
+            user_id: sys::DiscordUserId,
+            action: sys::EDiscordActivityActionType,
+            content: *const u8,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let content = str_arg(content);
+
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut state = backend.state();
+
+                if state.user(user_id).is_none() {
+                    return sys::DiscordResult_NotFound;
+                }
+
+                if state.activity.is_none() {
+                    return sys::DiscordResult_NoEligibleActivity;
+                }
+
+                state.sent_invites.push((user_id, action.into(), content));
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(send_invite)
+    },
+
+    accept_invite: {
+        unsafe extern "C" fn accept_invite(
+            mgr: *mut sys::IDiscordActivityManager,
+            user_id: sys::DiscordUserId,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut state = backend.state();
+
+                if state.user(user_id).is_none() {
+                    return sys::DiscordResult_NotFound;
+                }
+
+                state.accepted_invites.push(user_id);
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(accept_invite)
+    },
+};
+
+// Relationships
+
+const RELATIONSHIP_MANAGER: sys::IDiscordRelationshipManager = sys::IDiscordRelationshipManager {
+    filter: {
+        unsafe extern "C" fn filter(
+            mgr: *mut sys::IDiscordRelationshipManager,
+            filter_data: *mut c_void,
+            filter: Option<
+                unsafe extern "C" fn(*mut c_void, *mut sys::DiscordRelationship) -> bool,
+            >,
+        ) {
+            let backend = backend(mgr);
+
+            // The filter may call back into the instance, the state must not be borrowed
+            let relationships = backend.state().relationships.clone();
+
+            let filtered = relationships
+                .into_iter()
+                .filter(|relationship| {
+                    let mut relationship = *relationship;
+                    filter.unwrap()(filter_data, &mut relationship)
+                })
+                .collect();
+
+            backend.state().filtered_relationships = Some(filtered);
+        }
+
+        Some(filter)
+    },
+
+    count: {
+        unsafe extern "C" fn count(
+            mgr: *mut sys::IDiscordRelationshipManager,
+            count: *mut i32,
+        ) -> sys::EDiscordResult {
+            *count = backend(mgr).state().relationships().len() as i32;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(count)
+    },
+
+    get: {
+        unsafe extern "C" fn get(
+            mgr: *mut sys::IDiscordRelationshipManager,
+            user_id: sys::DiscordUserId,
+            relationship: *mut sys::DiscordRelationship,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .relationships
+                .iter()
+                .find(|rel| rel.user.id == user_id)
+            {
+                Some(rel) => {
+                    *relationship = *rel;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get)
+    },
+
+    get_at: {
+        unsafe extern "C" fn get_at(
+            mgr: *mut sys::IDiscordRelationshipManager,
+            index: u32,
+            relationship: *mut sys::DiscordRelationship,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state.relationships().get(index as usize) {
+                Some(rel) => {
+                    *relationship = *rel;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_at)
+    },
+};
+
+// Lobbies
+
+const LOBBY_TRANSACTION: sys::IDiscordLobbyTransaction = sys::IDiscordLobbyTransaction {
+    set_type: {
+        unsafe extern "C" fn set_type(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            kind: sys::EDiscordLobbyType,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction)).kind = Some(kind);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_type)
+    },
+
+    set_owner: {
+        unsafe extern "C" fn set_owner(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            owner_id: sys::DiscordUserId,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction)).owner_id = Some(owner_id);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_owner)
+    },
+
+    set_capacity: {
+        unsafe extern "C" fn set_capacity(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            capacity: u32,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction)).capacity = Some(capacity);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_capacity)
+    },
+
+    set_metadata: {
+        unsafe extern "C" fn set_metadata(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            key: *mut u8,
+            value: *mut u8,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction))
+                .metadata
+                .push((str_arg(key), Some(str_arg(value))));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_metadata)
+    },
+
+    delete_metadata: {
+        unsafe extern "C" fn delete_metadata(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            key: *mut u8,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction))
+                .metadata
+                .push((str_arg(key), None));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(delete_metadata)
+    },
+
+    set_locked: {
+        unsafe extern "C" fn set_locked(
+            tx: *mut sys::IDiscordLobbyTransaction,
+            locked: bool,
+        ) -> sys::EDiscordResult {
+            (*(tx as *mut LobbyTransaction)).locked = Some(locked);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_locked)
+    },
+};
+
+const LOBBY_MEMBER_TRANSACTION: sys::IDiscordLobbyMemberTransaction =
+    sys::IDiscordLobbyMemberTransaction {
+        set_metadata: {
+            unsafe extern "C" fn set_metadata(
+                tx: *mut sys::IDiscordLobbyMemberTransaction,
+                key: *mut u8,
+                value: *mut u8,
+            ) -> sys::EDiscordResult {
+                (*(tx as *mut LobbyMemberTransaction))
+                    .metadata
+                    .push((str_arg(key), Some(str_arg(value))));
+
+                sys::DiscordResult_Ok
+            }
+
+            Some(set_metadata)
+        },
+
+        delete_metadata: {
+            unsafe extern "C" fn delete_metadata(
+                tx: *mut sys::IDiscordLobbyMemberTransaction,
+                key: *mut u8,
+            ) -> sys::EDiscordResult {
+                (*(tx as *mut LobbyMemberTransaction))
+                    .metadata
+                    .push((str_arg(key), None));
+
+                sys::DiscordResult_Ok
+            }
+
+            Some(delete_metadata)
+        },
+    };
+
+const SEARCH_QUERY: sys::IDiscordLobbySearchQuery = sys::IDiscordLobbySearchQuery {
+    filter: {
+        unsafe extern "C" fn filter(
+            query: *mut sys::IDiscordLobbySearchQuery,
+            key: *mut u8,
+            comparison: sys::EDiscordLobbySearchComparison,
+            cast: sys::EDiscordLobbySearchCast,
+            value: *mut u8,
+        ) -> sys::EDiscordResult {
+            (*(query as *mut SearchQuery)).filters.push((
+                str_arg(key),
+                comparison,
+                cast,
+                str_arg(value),
+            ));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(filter)
+    },
+
+    sort: {
+        unsafe extern "C" fn sort(
+            query: *mut sys::IDiscordLobbySearchQuery,
+            key: *mut u8,
+            cast: sys::EDiscordLobbySearchCast,
+            value: *mut u8,
+        ) -> sys::EDiscordResult {
+            (*(query as *mut SearchQuery))
+                .sorts
+                .push((str_arg(key), cast, str_arg(value)));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(sort)
+    },
+
+    limit: {
+        unsafe extern "C" fn limit(
+            query: *mut sys::IDiscordLobbySearchQuery,
+            limit: u32,
+        ) -> sys::EDiscordResult {
+            (*(query as *mut SearchQuery)).limit = Some(limit);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(limit)
+    },
+
+    distance: {
+        // Every lobby is local
+        unsafe extern "C" fn distance(
+            _: *mut sys::IDiscordLobbySearchQuery,
+            _: sys::EDiscordLobbySearchDistance,
+        ) -> sys::EDiscordResult {
+            sys::DiscordResult_Ok
+        }
+
+        Some(distance)
+    },
+};
+
+fn new_lobby_transaction(backend: &MockBackend) -> *mut sys::IDiscordLobbyTransaction {
+    let tx = Box::into_raw(Box::new(LobbyTransaction {
+        vtable: LOBBY_TRANSACTION,
+        kind: None,
+        owner_id: None,
+        capacity: None,
+        locked: None,
+        metadata: Vec::new(),
+    }));
+
+    backend.state().lobby_transactions.push(tx);
+    tx as *mut sys::IDiscordLobbyTransaction
+}
+
+// Transactions are only valid until they are consumed
+unsafe fn take_lobby_transaction(
+    backend: &MockBackend,
+    tx: *mut sys::IDiscordLobbyTransaction,
+) -> Box<LobbyTransaction> {
+    let tx = tx as *mut LobbyTransaction;
+    backend.state().lobby_transactions.retain(|&ptr| ptr != tx);
+    Box::from_raw(tx)
+}
+
+unsafe fn take_member_transaction(
+    backend: &MockBackend,
+    tx: *mut sys::IDiscordLobbyMemberTransaction,
+) -> Box<LobbyMemberTransaction> {
+    let tx = tx as *mut LobbyMemberTransaction;
+    backend.state().member_transactions.retain(|&ptr| ptr != tx);
+    Box::from_raw(tx)
+}
+
+unsafe fn take_search_query(
+    backend: &MockBackend,
+    query: *mut sys::IDiscordLobbySearchQuery,
+) -> Box<SearchQuery> {
+    let query = query as *mut SearchQuery;
+    backend.state().search_queries.retain(|&ptr| ptr != query);
+    Box::from_raw(query)
+}
+
+fn apply_metadata(metadata: &mut Vec<(String, String)>, changes: &[(String, Option<String>)]) {
+    for (key, value) in changes {
+        match value {
+            Some(value) => state::set_metadata(metadata, key, value),
+            None => state::delete_metadata(metadata, key),
+        }
+    }
+}
+
+fn apply_lobby_transaction(lobby: &mut Lobby, tx: &LobbyTransaction) {
+    if let Some(kind) = tx.kind {
+        lobby.lobby.type_ = kind;
+    }
+
+    if let Some(owner_id) = tx.owner_id {
+        lobby.lobby.owner_id = owner_id;
+    }
+
+    if let Some(capacity) = tx.capacity {
+        lobby.lobby.capacity = capacity;
+    }
+
+    if let Some(locked) = tx.locked {
+        lobby.lobby.locked = locked;
+    }
+
+    apply_metadata(&mut lobby.metadata, &tx.metadata);
+}
+
+fn lobby_search_value(lobby: &Lobby, key: &str) -> Option<String> {
+    let mut parts = key.splitn(2, '.');
+
+    if let (Some("metadata"), Some(key)) = (parts.next(), parts.next()) {
+        return state::get_metadata(&lobby.metadata, key).map(ToString::to_string);
+    }
+
+    match key {
+        "id" => Some(lobby.lobby.id.to_string()),
+        "owner_id" => Some(lobby.lobby.owner_id.to_string()),
+        "capacity" => Some(lobby.lobby.capacity.to_string()),
+        "slots" => Some(
+            (lobby.lobby.capacity as usize)
+                .saturating_sub(lobby.members.len())
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+fn lobby_search_compare(
+    lhs: &str,
+    rhs: &str,
+    cast: sys::EDiscordLobbySearchCast,
+) -> Option<Ordering> {
+    match cast {
+        sys::DiscordLobbySearchCast_Number => lhs
+            .parse::<f64>()
+            .ok()?
+            .partial_cmp(&rhs.parse::<f64>().ok()?),
+        _ => Some(lhs.cmp(rhs)),
+    }
+}
+
+fn lobby_search(state: &state::State, query: &SearchQuery) -> Vec<LobbyID> {
+    let mut lobbies: Vec<&Lobby> = state
+        .lobbies
+        .iter()
+        .filter(|lobby| lobby.lobby.type_ == sys::DiscordLobbyType_Public)
+        .filter(|lobby| {
+            query.filters.iter().all(|(key, comparison, cast, value)| {
+                let ordering = lobby_search_value(lobby, key)
+                    .and_then(|lhs| lobby_search_compare(&lhs, value, *cast));
+
+                match (ordering, *comparison) {
+                    (None, _) => false,
+                    (Some(ord), sys::DiscordLobbySearchComparison_LessThanOrEqual) => {
+                        ord != Ordering::Greater
+                    }
+                    (Some(ord), sys::DiscordLobbySearchComparison_LessThan) => {
+                        ord == Ordering::Less
+                    }
+                    (Some(ord), sys::DiscordLobbySearchComparison_Equal) => ord == Ordering::Equal,
+                    (Some(ord), sys::DiscordLobbySearchComparison_GreaterThan) => {
+                        ord == Ordering::Greater
+                    }
+                    (Some(ord), sys::DiscordLobbySearchComparison_GreaterThanOrEqual) => {
+                        ord != Ordering::Less
+                    }
+                    (Some(ord), sys::DiscordLobbySearchComparison_NotEqual) => {
+                        ord != Ordering::Equal
+                    }
+                    (Some(_), _) => false,
+                }
+            })
+        })
+        .collect();
+
+    // Lobbies closest to the sort value come first
+    lobbies.sort_by(|a, b| {
+        for (key, cast, value) in &query.sorts {
+            let distance = |lobby: &Lobby| {
+                let lhs = lobby_search_value(lobby, key)?;
+
+                match *cast {
+                    sys::DiscordLobbySearchCast_Number => {
+                        Some((lhs.parse::<f64>().ok()? - value.parse::<f64>().ok()?).abs())
+                    }
+                    _ => Some(if &lhs == value { 0.0 } else { 1.0 }),
+                }
+            };
+
+            let ordering = match (distance(a), distance(b)) {
+                (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    if let Some(limit) = query.limit {
+        lobbies.truncate(limit as usize);
+    }
+
+    lobbies.into_iter().map(|lobby| lobby.lobby.id).collect()
+}
+
+// Lobby operations that resolve with a `sys::DiscordLobby`
+fn respond_lobby(
+    backend: &MockBackend,
+    callback_data: *mut c_void,
+    callback: Option<
+        unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut sys::DiscordLobby),
+    >,
+    operation: impl 'static + FnOnce(&MockBackend) -> Result<sys::DiscordLobby, sys::EDiscordResult>,
+) {
+    backend.defer(move |backend, run| {
+        let (result, mut lobby) = if run {
+            match operation(backend) {
+                Ok(lobby) => (sys::DiscordResult_Ok, lobby),
+                Err(result) => (result, Default::default()),
+            }
+        } else {
+            (sys::DiscordResult_TransactionAborted, Default::default())
+        };
+
+        unsafe { callback.unwrap()(callback_data, result, &mut lobby) }
+    })
+}
+
+fn connect_lobby(
+    backend: &MockBackend,
+    lobby_id: LobbyID,
+    secret: &str,
+) -> Result<sys::DiscordLobby, sys::EDiscordResult> {
+    let mut state = backend.state();
+    let user_id = state.current_user.id;
+
+    let lobby = state
+        .lobby_mut(lobby_id)
+        .ok_or(sys::DiscordResult_NotFound)?;
+
+    if utils::charbuf_to_str(&lobby.lobby.secret) != secret {
+        return Err(sys::DiscordResult_InvalidLobbySecret);
+    }
+
+    if lobby.member(user_id).is_none() {
+        if lobby.lobby.locked && lobby.lobby.owner_id != user_id {
+            return Err(sys::DiscordResult_InvalidPermissions);
+        }
+
+        if lobby.members.len() >= lobby.lobby.capacity as usize {
+            return Err(sys::DiscordResult_LobbyFull);
+        }
+
+        lobby.members.push(Member::new(user_id));
+    }
+
+    Ok(lobby.lobby)
+}
+
+unsafe fn write_metadata_value(
+    metadata: &[(String, String)],
+    key: *mut u8,
+    value: *mut sys::DiscordMetadataValue,
+) -> sys::EDiscordResult {
+    match state::get_metadata(metadata, utils::charptr_to_str(key)) {
+        Some(v) => {
+            utils::write_charbuf(&mut *value, v);
+            sys::DiscordResult_Ok
+        }
+        None => sys::DiscordResult_NotFound,
+    }
+}
+
+unsafe fn write_metadata_key(
+    metadata: &[(String, String)],
+    index: i32,
+    key: *mut sys::DiscordMetadataKey,
+) -> sys::EDiscordResult {
+    match metadata.get(index as usize) {
+        Some((k, _)) => {
+            utils::write_charbuf(&mut *key, k);
+            sys::DiscordResult_Ok
+        }
+        None => sys::DiscordResult_NotFound,
+    }
+}
+
+const LOBBY_MANAGER: sys::IDiscordLobbyManager = sys::IDiscordLobbyManager {
+    get_lobby_create_transaction: {
+        unsafe extern "C" fn get_lobby_create_transaction(
+            mgr: *mut sys::IDiscordLobbyManager,
+            transaction: *mut *mut sys::IDiscordLobbyTransaction,
+        ) -> sys::EDiscordResult {
+            *transaction = new_lobby_transaction(&backend(mgr));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_lobby_create_transaction)
+    },
+
+    get_lobby_update_transaction: {
+        unsafe extern "C" fn get_lobby_update_transaction(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            transaction: *mut *mut sys::IDiscordLobbyTransaction,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+
+            if backend.state().lobby(lobby_id).is_none() {
+                return sys::DiscordResult_NotFound;
+            }
+
+            *transaction = new_lobby_transaction(&backend);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_lobby_update_transaction)
+    },
+
+    get_member_update_transaction: {
+        unsafe extern "C" fn get_member_update_transaction(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            transaction: *mut *mut sys::IDiscordLobbyMemberTransaction,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let mut state = backend.state();
+
+            if state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+                .is_none()
+            {
+                return sys::DiscordResult_NotFound;
+            }
+
+            let tx = Box::into_raw(Box::new(LobbyMemberTransaction {
+                vtable: LOBBY_MEMBER_TRANSACTION,
+                metadata: Vec::new(),
+            }));
+
+            state.member_transactions.push(tx);
+            *transaction = tx as *mut sys::IDiscordLobbyMemberTransaction;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_member_update_transaction)
+    },
+
+    create_lobby: {
+        unsafe extern "C" fn create_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            transaction: *mut sys::IDiscordLobbyTransaction,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut sys::DiscordLobby),
+            >,
+        ) {
+            let backend = backend(mgr);
+            let tx = take_lobby_transaction(&backend, transaction);
+
+            respond_lobby(&backend, callback_data, callback, move |backend| {
+                let mut state = backend.state();
+                let id = state.next_id();
+                let user_id = state.current_user.id;
+
+                let mut lobby = Lobby {
+                    lobby: sys::DiscordLobby {
+                        id,
+                        type_: sys::DiscordLobbyType_Private,
+                        owner_id: user_id,
+                        capacity: 16,
+                        ..Default::default()
+                    },
+                    metadata: Vec::new(),
+                    members: vec![Member::new(user_id)],
+                };
+
+                utils::write_charbuf(&mut lobby.lobby.secret, &format!("mock-secret-{}", id));
+                apply_lobby_transaction(&mut lobby, &tx);
+
+                let result = lobby.lobby;
+                state.lobbies.push(lobby);
+
+                Ok(result)
+            })
+        }
+
+        Some(create_lobby)
+    },
+
+    update_lobby: {
+        unsafe extern "C" fn update_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            transaction: *mut sys::IDiscordLobbyTransaction,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let backend = backend(mgr);
+            let tx = take_lobby_transaction(&backend, transaction);
+
+            respond(&backend, callback_data, callback, move |backend| {
+                {
+                    let mut state = backend.state();
+                    let user_id = state.current_user.id;
+
+                    let lobby = match state.lobby_mut(lobby_id) {
+                        Some(lobby) => lobby,
+                        None => return sys::DiscordResult_NotFound,
+                    };
+
+                    if lobby.lobby.owner_id != user_id {
+                        return sys::DiscordResult_InvalidPermissions;
+                    }
+
+                    apply_lobby_transaction(lobby, &tx);
+                }
+
+                backend.fire(|params| {
+                    (*params.lobby_events).on_lobby_update.unwrap()(params.event_data, lobby_id)
+                });
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(update_lobby)
+    },
+
+    delete_lobby: {
+        unsafe extern "C" fn delete_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                {
+                    let mut state = backend.state();
+                    let user_id = state.current_user.id;
+
+                    match state.lobby(lobby_id) {
+                        None => return sys::DiscordResult_NotFound,
+                        Some(lobby) if lobby.lobby.owner_id != user_id => {
+                            return sys::DiscordResult_InvalidPermissions
+                        }
+                        Some(_) => {}
+                    }
+
+                    state.lobbies.retain(|lobby| lobby.lobby.id != lobby_id);
+                    state.search_results.retain(|&id| id != lobby_id);
+                }
+
+                backend.fire(|params| {
+                    (*params.lobby_events).on_lobby_delete.unwrap()(params.event_data, lobby_id, 0)
+                });
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(delete_lobby)
+    },
+
+    connect_lobby: {
+        unsafe extern "C" fn connect_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            secret: *mut u8,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut sys::DiscordLobby),
+            >,
+        ) {
+            let secret = str_arg(secret);
+
+            respond_lobby(&backend(mgr), callback_data, callback, move |backend| {
+                self::connect_lobby(backend, lobby_id, &secret)
+            })
+        }
+
+        Some(connect_lobby)
+    },
+
+    connect_lobby_with_activity_secret: {
+        unsafe extern "C" fn connect_lobby_with_activity_secret(
+            mgr: *mut sys::IDiscordLobbyManager,
+            activity_secret: *mut u8,
+            callback_data: *mut c_void,
+            callback: Option<
+                unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut sys::DiscordLobby),
+            >,
+        ) {
+            let activity_secret = str_arg(activity_secret);
+
+            respond_lobby(&backend(mgr), callback_data, callback, move |backend| {
+                let mut parts = activity_secret.splitn(2, ':');
+
+                let lobby_id = parts
+                    .next()
+                    .and_then(|id| id.parse().ok())
+                    .ok_or(sys::DiscordResult_InvalidSecret)?;
+
+                let secret = parts.next().ok_or(sys::DiscordResult_InvalidSecret)?;
+
+                self::connect_lobby(backend, lobby_id, secret)
+            })
+        }
+
+        Some(connect_lobby_with_activity_secret)
+    },
+
+    disconnect_lobby: {
+        unsafe extern "C" fn disconnect_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut state = backend.state();
+                let user_id = state.current_user.id;
+
+                match state.lobby_mut(lobby_id) {
+                    Some(lobby) if lobby.member(user_id).is_some() => {
+                        lobby.members.retain(|member| member.user_id != user_id);
+                        sys::DiscordResult_Ok
+                    }
+                    _ => sys::DiscordResult_NotFound,
+                }
+            })
+        }
+
+        Some(disconnect_lobby)
+    },
+
+    get_lobby: {
+        unsafe extern "C" fn get_lobby(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            lobby: *mut sys::DiscordLobby,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(l) => {
+                    *lobby = l.lobby;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_lobby)
+    },
+
+    get_lobby_activity_secret: {
+        unsafe extern "C" fn get_lobby_activity_secret(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            secret: *mut sys::DiscordLobbySecret,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(lobby) => {
+                    utils::write_charbuf(
+                        &mut *secret,
+                        &format!(
+                            "{}:{}",
+                            lobby_id,
+                            utils::charbuf_to_str(&lobby.lobby.secret)
+                        ),
+                    );
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_lobby_activity_secret)
+    },
+
+    get_lobby_metadata_value: {
+        unsafe extern "C" fn get_lobby_metadata_value(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            key: *mut u8,
+            value: *mut sys::DiscordMetadataValue,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(lobby) => write_metadata_value(&lobby.metadata, key, value),
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_lobby_metadata_value)
+    },
+
+    get_lobby_metadata_key: {
+        unsafe extern "C" fn get_lobby_metadata_key(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            index: i32,
+            key: *mut sys::DiscordMetadataKey,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(lobby) => write_metadata_key(&lobby.metadata, index, key),
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_lobby_metadata_key)
+    },
+
+    lobby_metadata_count: {
+        unsafe extern "C" fn lobby_metadata_count(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            count: *mut i32,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(lobby) => {
+                    *count = lobby.metadata.len() as i32;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(lobby_metadata_count)
+    },
+
+    member_count: {
+        unsafe extern "C" fn member_count(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            count: *mut i32,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().lobby(lobby_id) {
+                Some(lobby) => {
+                    *count = lobby.members.len() as i32;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(member_count)
+    },
+
+    get_member_user_id: {
+        unsafe extern "C" fn get_member_user_id(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            index: i32,
+            user_id: *mut sys::DiscordUserId,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.members.get(index as usize))
+            {
+                Some(member) => {
+                    *user_id = member.user_id;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_member_user_id)
+    },
+
+    get_member_user: {
+        unsafe extern "C" fn get_member_user(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            user: *mut sys::DiscordUser,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            if state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+                .is_none()
+            {
+                return sys::DiscordResult_NotFound;
+            }
+
+            match state.user(user_id) {
+                Some(u) => {
+                    *user = u;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_member_user)
+    },
+
+    get_member_metadata_value: {
+        unsafe extern "C" fn get_member_metadata_value(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            key: *mut u8,
+            value: *mut sys::DiscordMetadataValue,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+            {
+                Some(member) => write_metadata_value(&member.metadata, key, value),
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_member_metadata_value)
+    },
+
+    get_member_metadata_key: {
+        unsafe extern "C" fn get_member_metadata_key(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            index: i32,
+            key: *mut sys::DiscordMetadataKey,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+            {
+                Some(member) => write_metadata_key(&member.metadata, index, key),
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_member_metadata_key)
+    },
+
+    member_metadata_count: {
+        unsafe extern "C" fn member_metadata_count(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            count: *mut i32,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+            {
+                Some(member) => {
+                    *count = member.metadata.len() as i32;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(member_metadata_count)
+    },
+
+    update_member: {
+        unsafe extern "C" fn update_member(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            transaction: *mut sys::IDiscordLobbyMemberTransaction,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let backend = backend(mgr);
+            let tx = take_member_transaction(&backend, transaction);
+
+            respond(&backend, callback_data, callback, move |backend| {
+                {
+                    let mut state = backend.state();
+
+                    match state
+                        .lobby_mut(lobby_id)
+                        .and_then(|lobby| lobby.member_mut(user_id))
+                    {
+                        Some(member) => apply_metadata(&mut member.metadata, &tx.metadata),
+                        None => return sys::DiscordResult_NotFound,
+                    }
+                }
+
+                backend.fire(|params| {
+                    (*params.lobby_events).on_member_update.unwrap()(
+                        params.event_data,
+                        lobby_id,
+                        user_id,
+                    )
+                });
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(update_member)
+    },
+
+    send_lobby_message: {
+        unsafe extern "C" fn send_lobby_message(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            data: *mut u8,
+            data_length: u32,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let data = std::slice::from_raw_parts(data, data_length as usize).to_vec();
+
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let user_id = {
+                    let mut state = backend.state();
+                    let user_id = state.current_user.id;
+
+                    if state
+                        .lobby(lobby_id)
+                        .and_then(|lobby| lobby.member(user_id))
+                        .is_none()
+                    {
+                        return sys::DiscordResult_NotFound;
+                    }
+
+                    state.sent_lobby_messages.push((lobby_id, data.clone()));
+                    user_id
+                };
+
+                // Messages are echoed back to the sender
+                let mut data = data;
+                backend.fire(|params| {
+                    (*params.lobby_events).on_lobby_message.unwrap()(
+                        params.event_data,
+                        lobby_id,
+                        user_id,
+                        data.as_mut_ptr(),
+                        data.len() as u32,
+                    )
+                });
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(send_lobby_message)
+    },
+
+    get_search_query: {
+        unsafe extern "C" fn get_search_query(
+            mgr: *mut sys::IDiscordLobbyManager,
+            query: *mut *mut sys::IDiscordLobbySearchQuery,
+        ) -> sys::EDiscordResult {
+            let ptr = Box::into_raw(Box::new(SearchQuery {
+                vtable: SEARCH_QUERY,
+                filters: Vec::new(),
+                sorts: Vec::new(),
+                limit: None,
+            }));
+
+            backend(mgr).state().search_queries.push(ptr);
+            *query = ptr as *mut sys::IDiscordLobbySearchQuery;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_search_query)
+    },
+
+    search: {
+        unsafe extern "C" fn search(
+            mgr: *mut sys::IDiscordLobbyManager,
+            query: *mut sys::IDiscordLobbySearchQuery,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let backend = backend(mgr);
+            let query = take_search_query(&backend, query);
+
+            respond(&backend, callback_data, callback, move |backend| {
+                let mut state = backend.state();
+                state.search_results = lobby_search(&state, &query);
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(search)
+    },
+
+    lobby_count: {
+        unsafe extern "C" fn lobby_count(mgr: *mut sys::IDiscordLobbyManager, count: *mut i32) {
+            *count = backend(mgr).state().search_results.len() as i32;
+        }
+
+        Some(lobby_count)
+    },
+
+    get_lobby_id: {
+        unsafe extern "C" fn get_lobby_id(
+            mgr: *mut sys::IDiscordLobbyManager,
+            index: i32,
+            lobby_id: *mut sys::DiscordLobbyId,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().search_results.get(index as usize) {
+                Some(&id) => {
+                    *lobby_id = id;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_lobby_id)
+    },
+
+    connect_voice: {
+        unsafe extern "C" fn connect_voice(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                set_member_flag(backend, lobby_id, |member| member.voice_connected = true)
+            })
+        }
+
+        Some(connect_voice)
+    },
+
+    disconnect_voice: {
+        unsafe extern "C" fn disconnect_voice(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                set_member_flag(backend, lobby_id, |member| member.voice_connected = false)
+            })
+        }
+
+        Some(disconnect_voice)
+    },
+
+    connect_network: {
+        unsafe extern "C" fn connect_network(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+        ) -> sys::EDiscordResult {
+            set_member_flag(&backend(mgr), lobby_id, |member| {
+                member.network_connected = true
+            })
+        }
+
+        Some(connect_network)
+    },
+
+    disconnect_network: {
+        unsafe extern "C" fn disconnect_network(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+        ) -> sys::EDiscordResult {
+            set_member_flag(&backend(mgr), lobby_id, |member| {
+                member.network_connected = false
+            })
+        }
+
+        Some(disconnect_network)
+    },
+
+    flush_network: {
+        unsafe extern "C" fn flush_network(
+            _: *mut sys::IDiscordLobbyManager,
+        ) -> sys::EDiscordResult {
+            sys::DiscordResult_Ok
+        }
+
+        Some(flush_network)
+    },
+
+    open_network_channel: {
+        unsafe extern "C" fn open_network_channel(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            _channel_id: u8,
+            _reliable: bool,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(state.current_user.id))
+            {
+                Some(member) if member.network_connected => sys::DiscordResult_Ok,
+                _ => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(open_network_channel)
+    },
+
+    send_network_message: {
+        unsafe extern "C" fn send_network_message(
+            mgr: *mut sys::IDiscordLobbyManager,
+            lobby_id: sys::DiscordLobbyId,
+            user_id: sys::DiscordUserId,
+            channel_id: u8,
+            data: *mut u8,
+            data_length: u32,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let mut state = backend.state();
+
+            if state
+                .lobby(lobby_id)
+                .and_then(|lobby| lobby.member(user_id))
+                .is_none()
+            {
+                return sys::DiscordResult_NotFound;
+            }
+
+            let data = std::slice::from_raw_parts(data, data_length as usize).to_vec();
+
+            state
+                .sent_lobby_network_messages
+                .push((lobby_id, user_id, channel_id, data));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(send_network_message)
+    },
+};
+
+fn set_member_flag(
+    backend: &MockBackend,
+    lobby_id: LobbyID,
+    set: impl FnOnce(&mut Member),
+) -> sys::EDiscordResult {
+    let mut state = backend.state();
+    let user_id = state.current_user.id;
+
+    match state
+        .lobby_mut(lobby_id)
+        .and_then(|lobby| lobby.member_mut(user_id))
+    {
+        Some(member) => {
+            set(member);
+            sys::DiscordResult_Ok
+        }
+        None => sys::DiscordResult_NotFound,
+    }
+}
+
+// Networking
+
+const NETWORK_MANAGER: sys::IDiscordNetworkManager = sys::IDiscordNetworkManager {
+    get_peer_id: {
+        unsafe extern "C" fn get_peer_id(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: *mut sys::DiscordNetworkPeerId,
+        ) {
+            *peer_id = backend(mgr).state().peer_id;
+        }
+
+        Some(get_peer_id)
+    },
+
+    flush: {
+        unsafe extern "C" fn flush(_: *mut sys::IDiscordNetworkManager) -> sys::EDiscordResult {
+            sys::DiscordResult_Ok
+        }
+
+        Some(flush)
+    },
+
+    open_peer: {
+        unsafe extern "C" fn open_peer(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+            route_data: *const u8,
+        ) -> sys::EDiscordResult {
+            backend(mgr)
+                .state()
+                .peers
+                .insert(peer_id, str_arg(route_data));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(open_peer)
+    },
+
+    update_peer: {
+        unsafe extern "C" fn update_peer(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+            route_data: *const u8,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().peers.get_mut(&peer_id) {
+                Some(route) => {
+                    *route = str_arg(route_data);
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(update_peer)
+    },
+
+    close_peer: {
+        unsafe extern "C" fn close_peer(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let mut state = backend.state();
+
+            if state.peers.remove(&peer_id).is_none() {
+                return sys::DiscordResult_NotFound;
+            }
+
+            state.channels.retain(|&(peer, _), _| peer != peer_id);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(close_peer)
+    },
+
+    open_channel: {
+        unsafe extern "C" fn open_channel(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+            channel_id: sys::DiscordNetworkChannelId,
+            reliable: bool,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let mut state = backend.state();
+
+            if !state.peers.contains_key(&peer_id) {
+                return sys::DiscordResult_NotFound;
+            }
+
+            state.channels.insert((peer_id, channel_id), reliable);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(open_channel)
+    },
+
+    close_channel: {
+        unsafe extern "C" fn close_channel(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+            channel_id: sys::DiscordNetworkChannelId,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().channels.remove(&(peer_id, channel_id)) {
+                Some(_) => sys::DiscordResult_Ok,
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(close_channel)
+    },
+
+    send_message: {
+        unsafe extern "C" fn send_message(
+            mgr: *mut sys::IDiscordNetworkManager,
+            peer_id: sys::DiscordNetworkPeerId,
+            channel_id: sys::DiscordNetworkChannelId,
+            data: *mut u8,
+            data_length: u32,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let mut state = backend.state();
+
+            if !state.channels.contains_key(&(peer_id, channel_id)) {
+                return sys::DiscordResult_NotFound;
+            }
+
+            let data = std::slice::from_raw_parts(data, data_length as usize).to_vec();
+
+            state
+                .sent_network_messages
+                .push((peer_id, channel_id, data));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(send_message)
+    },
+};
+
+// Overlay
+
+const OVERLAY_MANAGER: sys::IDiscordOverlayManager = sys::IDiscordOverlayManager {
+    is_enabled: {
+        unsafe extern "C" fn is_enabled(mgr: *mut sys::IDiscordOverlayManager, enabled: *mut bool) {
+            *enabled = backend(mgr).state().overlay_enabled;
+        }
+
+        Some(is_enabled)
+    },
+
+    is_locked: {
+        unsafe extern "C" fn is_locked(mgr: *mut sys::IDiscordOverlayManager, locked: *mut bool) {
+            *locked = backend(mgr).state().overlay_locked;
+        }
+
+        Some(is_locked)
+    },
+
+    set_locked: {
+        unsafe extern "C" fn set_locked(
+            mgr: *mut sys::IDiscordOverlayManager,
+            locked: bool,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let changed = {
+                    let mut state = backend.state();
+
+                    if !state.overlay_enabled {
+                        return sys::DiscordResult_InvalidCommand;
+                    }
+
+                    let changed = state.overlay_locked != locked;
+                    state.overlay_locked = locked;
+                    changed
+                };
+
+                if changed {
+                    backend.fire(|params| {
+                        (*params.overlay_events).on_toggle.unwrap()(params.event_data, locked)
+                    });
+                }
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(set_locked)
+    },
+
+    open_activity_invite: {
+        unsafe extern "C" fn open_activity_invite(
+            mgr: *mut sys::IDiscordOverlayManager,
+            _action: sys::EDiscordActivityActionType,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, overlay_result)
+        }
+
+        Some(open_activity_invite)
+    },
+
+    open_guild_invite: {
+        unsafe extern "C" fn open_guild_invite(
+            mgr: *mut sys::IDiscordOverlayManager,
+            _code: *const u8,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, overlay_result)
+        }
+
+        Some(open_guild_invite)
+    },
+
+    open_voice_settings: {
+        unsafe extern "C" fn open_voice_settings(
+            mgr: *mut sys::IDiscordOverlayManager,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, overlay_result)
+        }
+
+        Some(open_voice_settings)
+    },
+};
+
+fn overlay_result(backend: &MockBackend) -> sys::EDiscordResult {
+    if backend.state().overlay_enabled {
+        sys::DiscordResult_Ok
+    } else {
+        sys::DiscordResult_InvalidCommand
+    }
+}
+
+// Storage
+
+unsafe fn write_file_stat(stat: *mut sys::DiscordFileStat, filename: &str, file: &File) {
+    *stat = sys::DiscordFileStat {
+        size: file.data.len() as u64,
+        last_modified: file.last_modified as u64,
+        ..Default::default()
+    };
+
+    utils::write_charbuf(&mut (*stat).filename, filename);
+}
+
+// Storage operations that resolve with a buffer
+fn respond_data(
+    backend: &MockBackend,
+    callback_data: *mut c_void,
+    callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut u8, u32)>,
+    operation: impl 'static + FnOnce(&MockBackend) -> Result<Vec<u8>, sys::EDiscordResult>,
+) {
+    backend.defer(move |backend, run| {
+        let (result, mut data) = if run {
+            match operation(backend) {
+                Ok(data) => (sys::DiscordResult_Ok, data),
+                Err(result) => (result, Vec::new()),
+            }
+        } else {
+            (sys::DiscordResult_TransactionAborted, Vec::new())
+        };
+
+        unsafe { callback.unwrap()(callback_data, result, data.as_mut_ptr(), data.len() as u32) }
+    })
+}
+
+const STORAGE_MANAGER: sys::IDiscordStorageManager = sys::IDiscordStorageManager {
+    read: {
+        unsafe extern "C" fn read(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            data: *mut u8,
+            data_length: u32,
+            read: *mut u32,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            let file = match state.files.get(utils::charptr_to_str(name)) {
+                Some(file) => file,
+                None => return sys::DiscordResult_NotFound,
+            };
+
+            let len = file.data.len().min(data_length as usize);
+
+            std::slice::from_raw_parts_mut(data, len).copy_from_slice(&file.data[..len]);
+            *read = len as u32;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(read)
+    },
 
-const CORE: &sys::IDiscordCore = &sys::IDiscordCore {
-    destroy: {
-        unsafe extern "C" fn destroy(_: *mut sys::IDiscordCore) {
-            while let Some(closure) = STATE.as_mut().unwrap().queue.pop() {
-                closure()
+    read_async: {
+        unsafe extern "C" fn read_async(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            callback_data: *mut c_void,
+            callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut u8, u32)>,
+        ) {
+            let name = str_arg(name);
+
+            respond_data(
+                &backend(mgr),
+                callback_data,
+                callback,
+                move |backend| match backend.state().files.get(&name) {
+                    Some(file) => Ok(file.data.clone()),
+                    None => Err(sys::DiscordResult_NotFound),
+                },
+            )
+        }
+
+        Some(read_async)
+    },
+
+    read_async_partial: {
+        unsafe extern "C" fn read_async_partial(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            offset: u64,
+            length: u64,
+            callback_data: *mut c_void,
+            callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult, *mut u8, u32)>,
+        ) {
+            let name = str_arg(name);
+
+            respond_data(
+                &backend(mgr),
+                callback_data,
+                callback,
+                move |backend| match backend.state().files.get(&name) {
+                    Some(file) => {
+                        let start = (offset as usize).min(file.data.len());
+                        let end = start.saturating_add(length as usize).min(file.data.len());
+                        Ok(file.data[start..end].to_vec())
+                    }
+                    None => Err(sys::DiscordResult_NotFound),
+                },
+            )
+        }
+
+        Some(read_async_partial)
+    },
+
+    write: {
+        unsafe extern "C" fn write(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            data: *mut u8,
+            data_length: u32,
+        ) -> sys::EDiscordResult {
+            let data = std::slice::from_raw_parts(data, data_length as usize).to_vec();
+
+            backend(mgr).state().files.insert(
+                str_arg(name),
+                File {
+                    data,
+                    last_modified: state::now(),
+                },
+            );
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(write)
+    },
+
+    write_async: {
+        unsafe extern "C" fn write_async(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            data: *mut u8,
+            data_length: u32,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            let name = str_arg(name);
+            let data = std::slice::from_raw_parts(data, data_length as usize).to_vec();
+
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                backend.state().files.insert(
+                    name,
+                    File {
+                        data,
+                        last_modified: state::now(),
+                    },
+                );
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(write_async)
+    },
+
+    delete_: {
+        unsafe extern "C" fn delete_(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+        ) -> sys::EDiscordResult {
+            match backend(mgr)
+                .state()
+                .files
+                .remove(utils::charptr_to_str(name))
+            {
+                Some(_) => sys::DiscordResult_Ok,
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(delete_)
+    },
+
+    exists: {
+        unsafe extern "C" fn exists(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            exists: *mut bool,
+        ) -> sys::EDiscordResult {
+            *exists = backend(mgr)
+                .state()
+                .files
+                .contains_key(utils::charptr_to_str(name));
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(exists)
+    },
+
+    count: {
+        unsafe extern "C" fn count(mgr: *mut sys::IDiscordStorageManager, count: *mut i32) {
+            *count = backend(mgr).state().files.len() as i32;
+        }
+
+        Some(count)
+    },
+
+    stat: {
+        unsafe extern "C" fn stat(
+            mgr: *mut sys::IDiscordStorageManager,
+            name: *const u8,
+            stat: *mut sys::DiscordFileStat,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+            let name = utils::charptr_to_str(name);
+
+            match state.files.get(name) {
+                Some(file) => {
+                    write_file_stat(stat, name, file);
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(stat)
+    },
+
+    stat_at: {
+        unsafe extern "C" fn stat_at(
+            mgr: *mut sys::IDiscordStorageManager,
+            index: i32,
+            stat: *mut sys::DiscordFileStat,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            let state = backend.state();
+
+            match state.files.iter().nth(index as usize) {
+                Some((name, file)) => {
+                    write_file_stat(stat, name, file);
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(stat_at)
+    },
+
+    get_path: {
+        unsafe extern "C" fn get_path(
+            mgr: *mut sys::IDiscordStorageManager,
+            path: *mut sys::DiscordPath,
+        ) -> sys::EDiscordResult {
+            utils::write_charbuf(&mut *path, &backend(mgr).state().folder_path);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_path)
+    },
+};
+
+// Store
+
+const STORE_MANAGER: sys::IDiscordStoreManager = sys::IDiscordStoreManager {
+    fetch_skus: {
+        unsafe extern "C" fn fetch_skus(
+            mgr: *mut sys::IDiscordStoreManager,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, |_| {
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(fetch_skus)
+    },
+
+    count_skus: {
+        unsafe extern "C" fn count_skus(mgr: *mut sys::IDiscordStoreManager, count: *mut i32) {
+            *count = backend(mgr).state().skus.len() as i32;
+        }
+
+        Some(count_skus)
+    },
+
+    get_sku: {
+        unsafe extern "C" fn get_sku(
+            mgr: *mut sys::IDiscordStoreManager,
+            sku_id: sys::DiscordSnowflake,
+            sku: *mut sys::DiscordSku,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().skus.iter().find(|s| s.id == sku_id) {
+                Some(s) => {
+                    *sku = *s;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
+        }
+
+        Some(get_sku)
+    },
+
+    get_sku_at: {
+        unsafe extern "C" fn get_sku_at(
+            mgr: *mut sys::IDiscordStoreManager,
+            index: i32,
+            sku: *mut sys::DiscordSku,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().skus.get(index as usize) {
+                Some(s) => {
+                    *sku = *s;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
             }
+        }
+
+        Some(get_sku_at)
+    },
+
+    fetch_entitlements: {
+        unsafe extern "C" fn fetch_entitlements(
+            mgr: *mut sys::IDiscordStoreManager,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, |_| {
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(fetch_entitlements)
+    },
+
+    count_entitlements: {
+        unsafe extern "C" fn count_entitlements(
+            mgr: *mut sys::IDiscordStoreManager,
+            count: *mut i32,
+        ) {
+            *count = backend(mgr).state().entitlements.len() as i32;
+        }
+
+        Some(count_entitlements)
+    },
 
-            drop(STATE.take());
+    get_entitlement: {
+        unsafe extern "C" fn get_entitlement(
+            mgr: *mut sys::IDiscordStoreManager,
+            entitlement_id: sys::DiscordSnowflake,
+            entitlement: *mut sys::DiscordEntitlement,
+        ) -> sys::EDiscordResult {
+            match backend(mgr)
+                .state()
+                .entitlements
+                .iter()
+                .find(|e| e.id == entitlement_id)
+            {
+                Some(e) => {
+                    *entitlement = *e;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
         }
 
-        Some(destroy)
+        Some(get_entitlement)
     },
 
-    run_callbacks: {
-        unsafe extern "C" fn run_callbacks(_: *mut sys::IDiscordCore) -> sys::EDiscordResult {
-            while let Some(closure) = STATE.as_mut().unwrap().queue.pop() {
-                closure()
+    get_entitlement_at: {
+        unsafe extern "C" fn get_entitlement_at(
+            mgr: *mut sys::IDiscordStoreManager,
+            index: i32,
+            entitlement: *mut sys::DiscordEntitlement,
+        ) -> sys::EDiscordResult {
+            match backend(mgr).state().entitlements.get(index as usize) {
+                Some(e) => {
+                    *entitlement = *e;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
             }
+        }
+
+        Some(get_entitlement_at)
+    },
+
+    has_sku_entitlement: {
+        unsafe extern "C" fn has_sku_entitlement(
+            mgr: *mut sys::IDiscordStoreManager,
+            sku_id: sys::DiscordSnowflake,
+            has_entitlement: *mut bool,
+        ) -> sys::EDiscordResult {
+            *has_entitlement = backend(mgr)
+                .state()
+                .entitlements
+                .iter()
+                .any(|e| e.sku_id == sku_id);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(has_sku_entitlement)
+    },
+
+    start_purchase: {
+        unsafe extern "C" fn start_purchase(
+            mgr: *mut sys::IDiscordStoreManager,
+            sku_id: sys::DiscordSnowflake,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut entitlement = {
+                    let mut state = backend.state();
+
+                    if state.skus.iter().all(|sku| sku.id != sku_id) {
+                        return sys::DiscordResult_NotFound;
+                    }
+
+                    let entitlement = sys::DiscordEntitlement {
+                        id: state.next_id(),
+                        type_: sys::DiscordEntitlementType_Purchase,
+                        sku_id,
+                    };
+
+                    state.entitlements.push(entitlement);
+                    entitlement
+                };
+
+                backend.fire(|params| {
+                    (*params.store_events).on_entitlement_create.unwrap()(
+                        params.event_data,
+                        &mut entitlement,
+                    )
+                });
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(start_purchase)
+    },
+};
+
+// Voice
+
+fn fire_voice_settings_update(backend: &MockBackend) {
+    backend.defer(|backend, run| {
+        if run {
+            backend.fire(|params| unsafe {
+                (*params.voice_events).on_settings_update.unwrap()(params.event_data)
+            })
+        }
+    })
+}
+
+const VOICE_MANAGER: sys::IDiscordVoiceManager = sys::IDiscordVoiceManager {
+    get_input_mode: {
+        unsafe extern "C" fn get_input_mode(
+            mgr: *mut sys::IDiscordVoiceManager,
+            input_mode: *mut sys::DiscordInputMode,
+        ) -> sys::EDiscordResult {
+            *input_mode = backend(mgr).state().input_mode;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(get_input_mode)
+    },
+
+    set_input_mode: {
+        unsafe extern "C" fn set_input_mode(
+            mgr: *mut sys::IDiscordVoiceManager,
+            input_mode: sys::DiscordInputMode,
+            callback_data: *mut c_void,
+            callback: Callback,
+        ) {
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                backend.state().input_mode = input_mode;
+                fire_voice_settings_update(backend);
+
+                sys::DiscordResult_Ok
+            })
+        }
+
+        Some(set_input_mode)
+    },
+
+    is_self_mute: {
+        unsafe extern "C" fn is_self_mute(
+            mgr: *mut sys::IDiscordVoiceManager,
+            mute: *mut bool,
+        ) -> sys::EDiscordResult {
+            *mute = backend(mgr).state().self_mute;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(is_self_mute)
+    },
+
+    set_self_mute: {
+        unsafe extern "C" fn set_self_mute(
+            mgr: *mut sys::IDiscordVoiceManager,
+            mute: bool,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            backend.state().self_mute = mute;
+            fire_voice_settings_update(&backend);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_self_mute)
+    },
+
+    is_self_deaf: {
+        unsafe extern "C" fn is_self_deaf(
+            mgr: *mut sys::IDiscordVoiceManager,
+            deaf: *mut bool,
+        ) -> sys::EDiscordResult {
+            *deaf = backend(mgr).state().self_deaf;
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(is_self_deaf)
+    },
+
+    set_self_deaf: {
+        unsafe extern "C" fn set_self_deaf(
+            mgr: *mut sys::IDiscordVoiceManager,
+            deaf: bool,
+        ) -> sys::EDiscordResult {
+            let backend = backend(mgr);
+            backend.state().self_deaf = deaf;
+            fire_voice_settings_update(&backend);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_self_deaf)
+    },
+
+    is_local_mute: {
+        unsafe extern "C" fn is_local_mute(
+            mgr: *mut sys::IDiscordVoiceManager,
+            user_id: sys::DiscordSnowflake,
+            mute: *mut bool,
+        ) -> sys::EDiscordResult {
+            *mute = backend(mgr)
+                .state()
+                .local_mutes
+                .get(&user_id)
+                .cloned()
+                .unwrap_or(false);
 
             sys::DiscordResult_Ok
         }
 
-        Some(run_callbacks)
+        Some(is_local_mute)
     },
 
-    get_achievement_manager: {
-        unsafe extern "C" fn get_achievement_manager(
-            _: *mut sys::IDiscordCore,
-        ) -> *mut sys::IDiscordAchievementManager {
-            ACHIEVEMENT_MANAGER as *const _ as *mut _
+    set_local_mute: {
+        unsafe extern "C" fn set_local_mute(
+            mgr: *mut sys::IDiscordVoiceManager,
+            user_id: sys::DiscordSnowflake,
+            mute: bool,
+        ) -> sys::EDiscordResult {
+            backend(mgr).state().local_mutes.insert(user_id, mute);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_local_mute)
+    },
+
+    get_local_volume: {
+        unsafe extern "C" fn get_local_volume(
+            mgr: *mut sys::IDiscordVoiceManager,
+            user_id: sys::DiscordSnowflake,
+            volume: *mut u8,
+        ) -> sys::EDiscordResult {
+            *volume = backend(mgr)
+                .state()
+                .local_volumes
+                .get(&user_id)
+                .cloned()
+                .unwrap_or(100);
+
+            sys::DiscordResult_Ok
         }
 
-        Some(get_achievement_manager)
+        Some(get_local_volume)
     },
 
-    set_log_hook: None,
-    get_application_manager: None,
-    get_user_manager: None,
-    get_image_manager: None,
-    get_activity_manager: None,
-    get_relationship_manager: None,
-    get_lobby_manager: None,
-    get_network_manager: None,
-    get_overlay_manager: None,
-    get_storage_manager: None,
-    get_store_manager: None,
-    get_voice_manager: None,
+    set_local_volume: {
+        unsafe extern "C" fn set_local_volume(
+            mgr: *mut sys::IDiscordVoiceManager,
+            user_id: sys::DiscordSnowflake,
+            volume: u8,
+        ) -> sys::EDiscordResult {
+            if volume > 200 {
+                return sys::DiscordResult_InvalidPayload;
+            }
+
+            backend(mgr).state().local_volumes.insert(user_id, volume);
+
+            sys::DiscordResult_Ok
+        }
+
+        Some(set_local_volume)
+    },
 };
 
-const ACHIEVEMENT_MANAGER: &sys::IDiscordAchievementManager = &sys::IDiscordAchievementManager {
+// Achievements
+
+const ACHIEVEMENT_MANAGER: sys::IDiscordAchievementManager = sys::IDiscordAchievementManager {
     set_user_achievement: {
-        unsafe extern "C" fn set_user_achievements(
-            _: *mut sys::IDiscordAchievementManager,
+        unsafe extern "C" fn set_user_achievement(
+            mgr: *mut sys::IDiscordAchievementManager,
             achievement_id: sys::DiscordSnowflake,
             percent_complete: u8,
             callback_data: *mut c_void,
-            callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult)>,
+            callback: Callback,
         ) {
-            STATE.as_mut().unwrap().queue.push(Box::new(move || {
-                let state = STATE.as_mut().unwrap();
+            respond(&backend(mgr), callback_data, callback, move |backend| {
+                let mut achievement = {
+                    let mut state = backend.state();
+                    let user_id = state.current_user.id;
+
+                    if percent_complete > 100 {
+                        return sys::DiscordResult_InvalidPayload;
+                    }
+
+                    let index = match state
+                        .achievements
+                        .iter()
+                        .position(|a| a.achievement_id == achievement_id)
+                    {
+                        Some(index) => index,
+                        None => {
+                            state.achievements.push(sys::DiscordUserAchievement {
+                                user_id,
+                                achievement_id,
+                                ..Default::default()
+                            });
+                            state.achievements.len() - 1
+                        }
+                    };
 
-                for achievement in state.achievements.iter_mut() {
-                    if achievement.achievement_id == achievement_id {
-                        achievement.percent_complete = percent_complete;
+                    let achievement = &mut state.achievements[index];
 
-                        (*state.params.achievement_events)
-                            .on_user_achievement_update
-                            .unwrap()(
-                            state.params.event_data, achievement as *const _ as *mut _
-                        )
+                    // Achievements can't go backwards
+                    if percent_complete < achievement.percent_complete {
+                        return sys::DiscordResult_Ok;
                     }
-                }
 
-                callback.unwrap()(callback_data, sys::DiscordResult_Ok);
-            }))
+                    achievement.percent_complete = percent_complete;
+
+                    if percent_complete == 100 && achievement.unlocked_at[0] == 0 {
+                        utils::write_charbuf(
+                            &mut achievement.unlocked_at,
+                            &state::now().to_string(),
+                        );
+                    }
+
+                    *achievement
+                };
+
+                backend.fire(|params| {
+                    (*params.achievement_events)
+                        .on_user_achievement_update
+                        .unwrap()(params.event_data, &mut achievement)
+                });
+
+                sys::DiscordResult_Ok
+            })
         }
 
-        Some(set_user_achievements)
+        Some(set_user_achievement)
     },
 
     fetch_user_achievements: {
         unsafe extern "C" fn fetch_user_achievements(
-            _: *mut sys::IDiscordAchievementManager,
+            mgr: *mut sys::IDiscordAchievementManager,
             callback_data: *mut c_void,
-            callback: Option<unsafe extern "C" fn(*mut c_void, sys::EDiscordResult)>,
+            callback: Callback,
         ) {
-            STATE.as_mut().unwrap().queue.push(Box::new(move || {
-                callback.unwrap()(callback_data, sys::DiscordResult_Ok);
-            }))
+            respond(&backend(mgr), callback_data, callback, |_| {
+                sys::DiscordResult_Ok
+            })
         }
 
         Some(fetch_user_achievements)
     },
 
+    count_user_achievements: {
+        unsafe extern "C" fn count_user_achievements(
+            mgr: *mut sys::IDiscordAchievementManager,
+            count: *mut i32,
+        ) {
+            *count = backend(mgr).state().achievements.len() as i32;
+        }
+
+        Some(count_user_achievements)
+    },
+
     get_user_achievement: {
         unsafe extern "C" fn get_user_achievement(
-            _: *mut sys::IDiscordAchievementManager,
+            mgr: *mut sys::IDiscordAchievementManager,
             user_achievement_id: sys::DiscordSnowflake,
             user_achievement: *mut sys::DiscordUserAchievement,
         ) -> sys::EDiscordResult {
-            for achievement in &STATE.as_ref().unwrap().achievements {
-                if achievement.achievement_id == user_achievement_id {
-                    *user_achievement = *achievement;
-
-                    return sys::DiscordResult_Ok;
+            match backend(mgr)
+                .state()
+                .achievements
+                .iter()
+                .find(|a| a.achievement_id == user_achievement_id)
+            {
+                Some(a) => {
+                    *user_achievement = *a;
+                    sys::DiscordResult_Ok
                 }
+                None => sys::DiscordResult_NotFound,
             }
-
-            sys::DiscordResult_NotFound
         }
 
         Some(get_user_achievement)
     },
 
-    count_user_achievements: {
-        unsafe extern "C" fn count_user_achievements(
-            _: *mut sys::IDiscordAchievementManager,
-            count: *mut i32,
-        ) {
-            *count = STATE.as_ref().unwrap().achievements.len() as i32;
-        }
-        Some(count_user_achievements)
-    },
-
     get_user_achievement_at: {
         unsafe extern "C" fn get_user_achievement_at(
-            _: *mut sys::IDiscordAchievementManager,
+            mgr: *mut sys::IDiscordAchievementManager,
             index: i32,
             user_achievement: *mut sys::DiscordUserAchievement,
         ) -> sys::EDiscordResult {
-            *user_achievement = STATE.as_ref().unwrap().achievements[index as usize];
-
-            sys::DiscordResult_Ok
+            match backend(mgr).state().achievements.get(index as usize) {
+                Some(a) => {
+                    *user_achievement = *a;
+                    sys::DiscordResult_Ok
+                }
+                None => sys::DiscordResult_NotFound,
+            }
         }
 
         Some(get_user_achievement_at)
     },
 };
-
-#[derive(Default)]
-struct State {
-    params: sys::DiscordCreateParams,
-    achievements: Vec<sys::DiscordUserAchievement>,
-    queue: Vec<Box<dyn FnOnce()>>,
-}
-
-static mut STATE: Option<State> = None;
-
-pub(crate) unsafe fn create_mock(params: sys::DiscordCreateParams) -> *mut sys::IDiscordCore {
-    if STATE.is_some() {
-        panic!("can only hold one instance lol");
-    }
-
-    STATE = Some(State {
-        params,
-        achievements: (0..10)
-            .map(|achievement_id| sys::DiscordUserAchievement {
-                user_id: 0,
-                achievement_id,
-                percent_complete: 0,
-                unlocked_at: [0; 64],
-            })
-            .collect(),
-        ..Default::default()
-    });
-
-    CORE as *const _ as *mut _
-}
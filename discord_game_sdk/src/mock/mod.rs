@@ -1,101 +1,1796 @@
 use crate::{
-    discord::{Discord, DiscordInner},
-    events, CreateFlags, EventHandler, UserAchievement,
+    sys, utils, Action, Activity, ClientID, CreateFlags, Discord, EntitlementKind, EventHandler,
+    ImageHandle, InputMode, LobbyID, LobbyKind, NetworkChannelID, NetworkPeerID, PremiumKind,
+    RelationshipKind, RequestReply, Result, SkuKind, Snowflake, Status, UnixTimestamp, UserFlags,
+    UserID,
+};
+use std::{
+    cell::{RefCell, RefMut},
+    rc::Rc,
 };
-use std::{cell::UnsafeCell, marker::PhantomData};
 
 mod ffi;
+mod state;
+
+/// In-process fake of the Discord client, implementing every manager
+///
+/// Lets an integration be tested without the Discord Game SDK library or a running client.
+/// Handles are cheap to clone and share the same state,
+/// one is kept to inject data and events into the [`Discord`] instance and inspect what it sent.
+///
+/// Operations and injected events are queued,
+/// they are resolved and fired in order during [`run_callbacks`].
+/// Only one [`Discord`] instance may use a backend at a time.
+///
+/// ```rust
+/// use discord_game_sdk::{Discord, EventHandler, LobbyID, LobbyKind, MockBackend, UserID};
+///
+/// #[derive(Default)]
+/// struct Joins(Vec<(LobbyID, UserID)>);
+///
+/// impl EventHandler for Joins {
+///     fn on_member_connect(&mut self, _: &Discord<'_, Self>, lobby_id: LobbyID, member_id: UserID) {
+///         self.0.push((lobby_id, member_id));
+///     }
+/// }
+///
+/// # fn main() -> discord_game_sdk::Result<()> {
+/// let backend = MockBackend::new();
+/// let mut discord = Discord::with_backend(0, &backend)?;
+/// *discord.event_handler_mut() = Some(Joins::default());
+///
+/// backend.add_user(2, "friend", "0002");
+/// let lobby_id = backend.add_lobby(discord.current_user()?.id(), LobbyKind::Public, 4);
+/// backend.connect_member(lobby_id, 2);
+///
+/// discord.run_callbacks()?;
+///
+/// assert_eq!(discord.event_handler().as_ref().unwrap().0, vec![(lobby_id, 2)]);
+/// assert_eq!(discord.lobby_member_count(lobby_id)?, 2);
+/// # Ok(()) }
+/// ```
+///
+/// [`Discord`]: struct.Discord.html
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+#[derive(Clone, Default)]
+pub struct MockBackend(Rc<RefCell<state::State>>);
 
 impl<E> Discord<'_, E> {
-    pub(crate) fn mock() -> Self
+    /// Creates an instance backed by a fresh [`MockBackend`](struct.MockBackend.html).
+    pub fn mock() -> Self
     where
         E: EventHandler,
     {
-        let mut instance = Discord(Box::into_raw(Box::new(DiscordInner {
-            _invariant_lifetime: PhantomData,
-
-            core: std::ptr::null_mut(),
-            client_id: 0,
-            event_handler: UnsafeCell::new(None),
-
-            achievement_events: events::achievement::<E>(),
-            activity_events: events::activity::<E>(),
-            lobby_events: events::lobby::<E>(),
-            network_events: events::network::<E>(),
-            overlay_events: events::overlay::<E>(),
-            relationship_events: events::relationship::<E>(),
-            store_events: events::store::<E>(),
-            user_events: events::user::<E>(),
-            voice_events: events::voice::<E>(),
-        })));
-
-        let params = instance.create_params(CreateFlags::Default.into());
-
-        instance.inner_mut().core = unsafe { ffi::create_mock(params) };
-
-        instance
-    }
-}
-
-#[test]
-fn miri_tests() {
-    struct E;
-
-    impl EventHandler for E {
-        fn on_user_achievement_update(
-            &mut self,
-            discord: &Discord<'_, Self>,
-            user_achievement: &UserAchievement,
-        ) {
-            for a in discord.iter_user_achievements() {
-                let a = a.unwrap();
-                eprintln!(
-                    "in event_handler {}: {}%",
-                    a.achievement_id(),
-                    a.percent_complete()
-                );
+        Self::with_backend(0, &MockBackend::new()).unwrap()
+    }
+
+    /// Creates an instance backed by a [`MockBackend`](struct.MockBackend.html).
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::Internal`](enum.Error.html#variant.Internal) if another instance
+    /// is already using `backend`.
+    pub fn with_backend(client_id: ClientID, backend: &MockBackend) -> Result<Self>
+    where
+        E: EventHandler,
+    {
+        Self::create(
+            client_id,
+            CreateFlags::Default,
+            |version, params, core| unsafe { ffi::create(backend, version, params, core) },
+        )
+    }
+}
+
+impl MockBackend {
+    /// Creates a backend with a default current user and no other data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn state(&self) -> RefMut<'_, state::State> {
+        self.0.borrow_mut()
+    }
+
+    pub(crate) fn defer(&self, task: impl 'static + FnOnce(&Self, bool)) {
+        self.state().queue.push_back(Box::new(task))
+    }
+
+    pub(crate) fn pop_task(&self) -> Option<state::Task> {
+        self.state().queue.pop_front()
+    }
+
+    // The state must not be borrowed while user code runs
+    pub(crate) fn fire(&self, event: impl FnOnce(&sys::DiscordCreateParams)) {
+        let params = self.state().params;
+
+        if let Some(params) = params {
+            event(&params)
+        }
+    }
+
+    fn queue_event(&self, event: impl 'static + FnOnce(&Self)) {
+        self.defer(|backend, run| {
+            if run {
+                event(backend)
             }
+        })
+    }
 
-            if user_achievement.percent_complete() == 99 {
-                discord.set_user_achievement(
-                    user_achievement.achievement_id(),
-                    100,
-                    |discord, _res| {
-                        for a in discord.iter_user_achievements() {
-                            let a = a.unwrap();
-                            eprintln!(
-                                "in event_handler in set {}: {}%",
-                                a.achievement_id(),
-                                a.percent_complete()
-                            );
-                        }
-                    },
-                );
+    fn user_or_default(state: &state::State, user_id: UserID) -> sys::DiscordUser {
+        state.user(user_id).unwrap_or_else(|| sys::DiscordUser {
+            id: user_id,
+            ..Default::default()
+        })
+    }
+
+    /// Simulates the Discord client starting or closing,
+    /// [`run_callbacks`](struct.Discord.html#method.run_callbacks) and creating an instance
+    /// return `Err(NotRunning)` while closed.
+    pub fn set_running(&self, running: bool) {
+        self.state().running = running;
+    }
+
+    /// Simulates an SDK library built for another version of the SDK,
+    /// creating an instance returns `Err(InvalidVersion)` unless `version` matches
+    /// [`sdk_versions`](fn.sdk_versions.html).
+    pub fn set_sdk_version(&self, version: sys::DiscordVersion) {
+        self.state().sdk_version = version;
+    }
+
+    /// Implements `DiscordCreate` with this backend, for libraries standing in for the SDK.
+    ///
+    /// See `discord_game_sdk_stub`, which exports it as `DiscordCreate`.
+    ///
+    /// ## Safety
+    ///
+    /// Same as the SDK's `DiscordCreate`: `params` must point to valid parameters, whose events
+    /// and `event_data` must stay valid until `destroy` is called, and `result` must be writable.
+    pub unsafe fn discord_create(
+        &self,
+        version: sys::DiscordVersion,
+        params: *mut sys::DiscordCreateParams,
+        result: *mut *mut sys::IDiscordCore,
+    ) -> sys::EDiscordResult {
+        ffi::create(self, version, params, result)
+    }
+}
+
+/// # Applications
+impl MockBackend {
+    /// Sets the locale returned by `current_locale`.
+    pub fn set_locale(&self, locale: &str) {
+        self.state().locale = locale.to_string();
+    }
+
+    /// Sets the branch returned by `current_branch`.
+    pub fn set_branch(&self, branch: &str) {
+        self.state().branch = branch.to_string();
+    }
+
+    /// Sets the token returned by `oauth2_token`.
+    pub fn set_oauth2_token(&self, access_token: &str, scopes: &str, expires: UnixTimestamp) {
+        let mut state = self.state();
+
+        state.oauth2_token = sys::DiscordOAuth2Token {
+            expires,
+            ..Default::default()
+        };
+
+        utils::write_charbuf(&mut state.oauth2_token.access_token, access_token);
+        utils::write_charbuf(&mut state.oauth2_token.scopes, scopes);
+    }
+
+    /// Sets the ticket returned by `app_ticket`.
+    pub fn set_app_ticket(&self, ticket: &str) {
+        self.state().app_ticket = ticket.to_string();
+    }
+}
+
+/// # Users
+impl MockBackend {
+    /// Replaces the current user and fires `on_current_user_update`.
+    pub fn set_current_user(&self, user_id: UserID, username: &str, discriminator: &str) {
+        {
+            let mut state = self.state();
+
+            state.current_user = sys::DiscordUser {
+                id: user_id,
+                ..Default::default()
+            };
+
+            utils::write_charbuf(&mut state.current_user.username, username);
+            utils::write_charbuf(&mut state.current_user.discriminator, discriminator);
+        }
+
+        self.queue_event(|backend| {
+            backend.fire(|params| unsafe {
+                (*params.user_events).on_current_user_update.unwrap()(params.event_data)
+            })
+        })
+    }
+
+    /// Sets the premium kind of the current user.
+    pub fn set_current_user_premium(&self, premium_kind: PremiumKind) {
+        self.state().premium_kind = premium_kind.into();
+    }
+
+    /// Sets the flags of the current user.
+    pub fn set_current_user_flags(&self, flags: UserFlags) {
+        self.state().user_flags = flags.bits();
+    }
+
+    /// Makes a user known to the user, lobby and relationship managers.
+    pub fn add_user(&self, user_id: UserID, username: &str, discriminator: &str) {
+        let mut user = sys::DiscordUser {
+            id: user_id,
+            ..Default::default()
+        };
+
+        utils::write_charbuf(&mut user.username, username);
+        utils::write_charbuf(&mut user.discriminator, discriminator);
+
+        let mut state = self.state();
+        state.users.retain(|u| u.id != user_id);
+        state.users.push(user);
+    }
+}
+
+/// # Images
+impl MockBackend {
+    /// Registers an image, unknown handles resolve to blank square images.
+    ///
+    /// `data` is RGBA, 4 bytes per pixel.
+    pub fn add_image(&self, handle: ImageHandle, width: u32, height: u32, data: Vec<u8>) {
+        let mut state = self.state();
+
+        state.images.retain(|image| image.handle != handle.0);
+        state.images.push(state::Image {
+            handle: handle.0,
+            width,
+            height,
+            data,
+        });
+    }
+}
+
+/// # Activities
+impl MockBackend {
+    /// The activity last set by `update_activity`, `None` if cleared.
+    pub fn activity(&self) -> Option<Activity> {
+        self.state().activity.map(Activity)
+    }
+
+    /// The command last set by `register_launch_command`.
+    pub fn launch_command(&self) -> Option<String> {
+        self.state().launch_command.clone()
+    }
+
+    /// The ID last set by `register_steam`.
+    pub fn steam_id(&self) -> Option<u32> {
+        self.state().steam_id
+    }
+
+    /// Invites sent with `send_invite`, in order.
+    pub fn sent_invites(&self) -> Vec<(UserID, Action, String)> {
+        self.state().sent_invites.clone()
+    }
+
+    /// Replies sent with `send_request_reply`, in order.
+    pub fn sent_request_replies(&self) -> Vec<(UserID, RequestReply)> {
+        self.state().sent_request_replies.clone()
+    }
+
+    /// Invites accepted with `accept_invite`, in order.
+    pub fn accepted_invites(&self) -> Vec<UserID> {
+        self.state().accepted_invites.clone()
+    }
+
+    /// Fires `on_activity_join`.
+    pub fn receive_join(&self, secret: &str) {
+        let mut secret = secret.to_string();
+        secret.push('\0');
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.activity_events).on_activity_join.unwrap()(
+                    params.event_data,
+                    secret.as_ptr(),
+                )
+            })
+        })
+    }
+
+    /// Fires `on_activity_spectate`.
+    pub fn receive_spectate(&self, secret: &str) {
+        let mut secret = secret.to_string();
+        secret.push('\0');
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.activity_events).on_activity_spectate.unwrap()(
+                    params.event_data,
+                    secret.as_ptr(),
+                )
+            })
+        })
+    }
+
+    /// Fires `on_activity_join_request` from a user previously added with `add_user`.
+    pub fn receive_join_request(&self, user_id: UserID) {
+        let mut user = Self::user_or_default(&self.state(), user_id);
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.activity_events).on_activity_join_request.unwrap()(
+                    params.event_data,
+                    &mut user,
+                )
+            })
+        })
+    }
+
+    /// Fires `on_activity_invite` from a user previously added with `add_user`.
+    pub fn receive_invite(&self, action: Action, user_id: UserID, activity: &Activity) {
+        let mut user = Self::user_or_default(&self.state(), user_id);
+        let mut activity = activity.0;
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.activity_events).on_activity_invite.unwrap()(
+                    params.event_data,
+                    action.into(),
+                    &mut user,
+                    &mut activity,
+                )
+            })
+        })
+    }
+}
+
+/// # Relationships
+impl MockBackend {
+    /// Creates or replaces the relationship with a user previously added with `add_user`,
+    /// and fires `on_relationship_update`.
+    pub fn set_relationship(
+        &self,
+        user_id: UserID,
+        kind: RelationshipKind,
+        status: Status,
+        activity: &Activity,
+    ) {
+        let mut relationship = {
+            let mut state = self.state();
+
+            let relationship = sys::DiscordRelationship {
+                type_: kind.into(),
+                user: Self::user_or_default(&state, user_id),
+                presence: sys::DiscordPresence {
+                    status: status.into(),
+                    activity: activity.0,
+                },
+            };
+
+            state.relationships.retain(|rel| rel.user.id != user_id);
+            state.relationships.push(relationship);
+
+            relationship
+        };
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.relationship_events)
+                    .on_relationship_update
+                    .unwrap()(params.event_data, &mut relationship)
+            })
+        })
+    }
+}
+
+/// # Lobbies
+///
+/// ## Panics
+///
+/// Methods taking a `LobbyID` panic if the lobby does not exist.
+impl MockBackend {
+    /// Creates a lobby owned by another user, which is connected to it.
+    ///
+    /// Its secret can be obtained through `lobby_activity_secret`.
+    pub fn add_lobby(&self, owner_id: UserID, kind: LobbyKind, capacity: u32) -> LobbyID {
+        let mut state = self.state();
+        let id = state.next_id();
+
+        let mut lobby = sys::DiscordLobby {
+            id,
+            type_: kind.into(),
+            owner_id,
+            capacity,
+            ..Default::default()
+        };
+
+        utils::write_charbuf(&mut lobby.secret, &format!("mock-secret-{}", id));
+
+        state.lobbies.push(state::Lobby {
+            lobby,
+            metadata: Vec::new(),
+            members: vec![state::Member::new(owner_id)],
+        });
+
+        id
+    }
+
+    fn lobby_mut(state: &mut state::State, lobby_id: LobbyID) -> &mut state::Lobby {
+        state
+            .lobby_mut(lobby_id)
+            .unwrap_or_else(|| panic!("mock lobby {} does not exist", lobby_id))
+    }
+
+    /// Sets a metadata entry of a lobby and fires `on_lobby_update`.
+    pub fn set_lobby_metadata(&self, lobby_id: LobbyID, key: &str, value: &str) {
+        state::set_metadata(
+            &mut Self::lobby_mut(&mut self.state(), lobby_id).metadata,
+            key,
+            value,
+        );
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_lobby_update.unwrap()(params.event_data, lobby_id)
+            })
+        })
+    }
+
+    /// Deletes a lobby and fires `on_lobby_delete`.
+    pub fn delete_lobby(&self, lobby_id: LobbyID, reason: u32) {
+        {
+            let mut state = self.state();
+            Self::lobby_mut(&mut state, lobby_id);
+            state.lobbies.retain(|lobby| lobby.lobby.id != lobby_id);
+            state.search_results.retain(|&id| id != lobby_id);
+        }
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_lobby_delete.unwrap()(params.event_data, lobby_id, reason)
+            })
+        })
+    }
+
+    /// Connects a user to a lobby and fires `on_member_connect`.
+    pub fn connect_member(&self, lobby_id: LobbyID, user_id: UserID) {
+        {
+            let mut state = self.state();
+            let lobby = Self::lobby_mut(&mut state, lobby_id);
+
+            if lobby.member(user_id).is_none() {
+                lobby.members.push(state::Member::new(user_id));
+            }
+        }
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_member_connect.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                )
+            })
+        })
+    }
+
+    /// Disconnects a user from a lobby and fires `on_member_disconnect`.
+    pub fn disconnect_member(&self, lobby_id: LobbyID, user_id: UserID) {
+        Self::lobby_mut(&mut self.state(), lobby_id)
+            .members
+            .retain(|member| member.user_id != user_id);
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_member_disconnect.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                )
+            })
+        })
+    }
+
+    /// Sets a metadata entry of a lobby member and fires `on_member_update`.
+    ///
+    /// ## Panics
+    ///
+    /// If the user is not connected to the lobby.
+    pub fn set_member_metadata(&self, lobby_id: LobbyID, user_id: UserID, key: &str, value: &str) {
+        {
+            let mut state = self.state();
+            let member = Self::lobby_mut(&mut state, lobby_id)
+                .member_mut(user_id)
+                .unwrap_or_else(|| panic!("mock user {} is not in the lobby", user_id));
+
+            state::set_metadata(&mut member.metadata, key, value);
+        }
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_member_update.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                )
+            })
+        })
+    }
+
+    /// Fires `on_lobby_message`.
+    pub fn receive_lobby_message(&self, lobby_id: LobbyID, user_id: UserID, mut data: Vec<u8>) {
+        Self::lobby_mut(&mut self.state(), lobby_id);
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_lobby_message.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                    data.as_mut_ptr(),
+                    data.len() as u32,
+                )
+            })
+        })
+    }
+
+    /// Fires `on_lobby_network_message`.
+    pub fn receive_lobby_network_message(
+        &self,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        channel_id: NetworkChannelID,
+        mut data: Vec<u8>,
+    ) {
+        Self::lobby_mut(&mut self.state(), lobby_id);
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_network_message.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                    channel_id,
+                    data.as_mut_ptr(),
+                    data.len() as u32,
+                )
+            })
+        })
+    }
+
+    /// Fires `on_speaking`.
+    pub fn set_speaking(&self, lobby_id: LobbyID, user_id: UserID, speaking: bool) {
+        Self::lobby_mut(&mut self.state(), lobby_id);
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.lobby_events).on_speaking.unwrap()(
+                    params.event_data,
+                    lobby_id,
+                    user_id,
+                    speaking,
+                )
+            })
+        })
+    }
+
+    /// Messages sent with `send_lobby_message`, in order.
+    pub fn sent_lobby_messages(&self) -> Vec<(LobbyID, Vec<u8>)> {
+        self.state().sent_lobby_messages.clone()
+    }
+
+    /// Messages sent with `send_lobby_network_message`, in order.
+    pub fn sent_lobby_network_messages(&self) -> Vec<(LobbyID, UserID, NetworkChannelID, Vec<u8>)> {
+        self.state().sent_lobby_network_messages.clone()
+    }
+}
+
+/// # Networking
+impl MockBackend {
+    /// Sets the current route and fires `on_network_route_update`.
+    pub fn set_route(&self, route: &str) {
+        self.state().route = route.to_string();
+
+        let mut route = route.to_string();
+        route.push('\0');
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.network_events).on_route_update.unwrap()(params.event_data, route.as_ptr())
+            })
+        })
+    }
+
+    /// Fires `on_network_message` if the channel with the peer was opened.
+    pub fn receive_network_message(
+        &self,
+        peer_id: NetworkPeerID,
+        channel_id: NetworkChannelID,
+        mut data: Vec<u8>,
+    ) {
+        self.queue_event(move |backend| {
+            if !backend
+                .state()
+                .channels
+                .contains_key(&(peer_id, channel_id))
+            {
+                return;
+            }
+
+            backend.fire(|params| unsafe {
+                (*params.network_events).on_message.unwrap()(
+                    params.event_data,
+                    peer_id,
+                    channel_id,
+                    data.as_mut_ptr(),
+                    data.len() as u32,
+                )
+            })
+        })
+    }
+
+    /// Messages sent with `send_message`, in order.
+    pub fn sent_network_messages(&self) -> Vec<(NetworkPeerID, NetworkChannelID, Vec<u8>)> {
+        self.state().sent_network_messages.clone()
+    }
+}
+
+/// # Overlay
+impl MockBackend {
+    /// Enables or disables the overlay.
+    pub fn set_overlay_enabled(&self, enabled: bool) {
+        self.state().overlay_enabled = enabled;
+    }
+
+    /// Opens or closes the overlay and fires `on_overlay_toggle`.
+    pub fn toggle_overlay(&self, closed: bool) {
+        self.state().overlay_locked = closed;
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.overlay_events).on_toggle.unwrap()(params.event_data, closed)
+            })
+        })
+    }
+}
+
+/// # Storage
+impl MockBackend {
+    /// The contents of a file.
+    pub fn file(&self, filename: &str) -> Option<Vec<u8>> {
+        self.state()
+            .files
+            .get(filename)
+            .map(|file| file.data.clone())
+    }
+
+    /// Creates or replaces a file.
+    pub fn set_file(&self, filename: &str, data: Vec<u8>) {
+        self.state().files.insert(
+            filename.to_string(),
+            state::File {
+                data,
+                last_modified: state::now(),
+            },
+        );
+    }
+}
+
+/// # Store
+impl MockBackend {
+    /// Adds a SKU, which can then be purchased with `start_purchase`.
+    pub fn add_sku(
+        &self,
+        sku_id: Snowflake,
+        kind: SkuKind,
+        name: &str,
+        price_amount: u32,
+        price_currency: &str,
+    ) {
+        let mut sku = sys::DiscordSku {
+            id: sku_id,
+            type_: kind.into(),
+            ..Default::default()
+        };
+
+        sku.price.amount = price_amount;
+        utils::write_charbuf(&mut sku.name, name);
+        utils::write_charbuf(&mut sku.price.currency, price_currency);
+
+        let mut state = self.state();
+        state.skus.retain(|s| s.id != sku_id);
+        state.skus.push(sku);
+    }
+
+    /// Grants an entitlement and fires `on_entitlement_create`.
+    pub fn grant_entitlement(&self, sku_id: Snowflake, kind: EntitlementKind) -> Snowflake {
+        let mut entitlement = {
+            let mut state = self.state();
+
+            let entitlement = sys::DiscordEntitlement {
+                id: state.next_id(),
+                type_: kind.into(),
+                sku_id,
+            };
+
+            state.entitlements.push(entitlement);
+            entitlement
+        };
+
+        let id = entitlement.id;
+
+        self.queue_event(move |backend| {
+            backend.fire(|params| unsafe {
+                (*params.store_events).on_entitlement_create.unwrap()(
+                    params.event_data,
+                    &mut entitlement,
+                )
+            })
+        });
+
+        id
+    }
+
+    /// Revokes an entitlement and fires `on_entitlement_delete`.
+    pub fn revoke_entitlement(&self, entitlement_id: Snowflake) {
+        let entitlement = {
+            let mut state = self.state();
+
+            let index = state
+                .entitlements
+                .iter()
+                .position(|e| e.id == entitlement_id);
+
+            index.map(|index| state.entitlements.remove(index))
+        };
+
+        if let Some(mut entitlement) = entitlement {
+            self.queue_event(move |backend| {
+                backend.fire(|params| unsafe {
+                    (*params.store_events).on_entitlement_delete.unwrap()(
+                        params.event_data,
+                        &mut entitlement,
+                    )
+                })
+            })
+        }
+    }
+}
+
+/// # Voice
+impl MockBackend {
+    /// Changes the voice settings as if done by the user and fires `on_voice_settings_update`.
+    pub fn set_voice_settings(&self, input_mode: InputMode, self_mute: bool, self_deaf: bool) {
+        {
+            let mut state = self.state();
+            state.input_mode = input_mode.0;
+            state.self_mute = self_mute;
+            state.self_deaf = self_deaf;
+        }
+
+        self.queue_event(|backend| {
+            backend.fire(|params| unsafe {
+                (*params.voice_events).on_settings_update.unwrap()(params.event_data)
+            })
+        })
+    }
+}
+
+/// # Achievements
+impl MockBackend {
+    /// Adds or replaces an achievement of the current user.
+    pub fn add_user_achievement(&self, achievement_id: Snowflake, percent_complete: u8) {
+        let mut state = self.state();
+
+        let achievement = sys::DiscordUserAchievement {
+            user_id: state.current_user.id,
+            achievement_id,
+            percent_complete,
+            ..Default::default()
+        };
+
+        state
+            .achievements
+            .retain(|a| a.achievement_id != achievement_id);
+        state.achievements.push(achievement);
+    }
+}
+
+impl std::fmt::Debug for MockBackend {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.0.borrow();
+
+        fmt.debug_struct("MockBackend")
+            .field("running", &state.running)
+            .field("in_use", &state.params.is_some())
+            .field("pending", &state.queue.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockBackend;
+    use crate::{
+        Action, Activity, Cast, Comparison, ConnectionState, Discord, DiscordDriver, Error, Event,
+        EventHandler, InviteInbox, LobbyID, LobbyKind, LobbySpec, LobbyTransaction,
+        PresenceManager, RelationshipKind, RequestReply, RetryPolicy, Scheduler, SearchQuery,
+        StateChange, StateMirror, Status, Supervisor, UserAchievement, UserID,
+    };
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn lobbies() {
+        #[derive(Default)]
+        struct E(Vec<(LobbyID, UserID, Vec<u8>)>);
+
+        impl EventHandler for E {
+            fn on_lobby_message(
+                &mut self,
+                _: &Discord<'_, Self>,
+                lobby_id: LobbyID,
+                member_id: UserID,
+                data: &[u8],
+            ) {
+                self.0.push((lobby_id, member_id, data.to_vec()));
+            }
+        }
+
+        let backend = MockBackend::new();
+        let mut discord = Discord::with_backend(0, &backend).unwrap();
+        *discord.event_handler_mut() = Some(E::default());
+
+        let created = Rc::new(RefCell::new(None));
+        let created_ = created.clone();
+
+        discord.create_lobby(
+            LobbyTransaction::new()
+                .capacity(2)
+                .add_metadata("map".into(), "de_dust2".into()),
+            move |discord, lobby| {
+                let lobby_id = lobby.unwrap().id();
+                *created_.borrow_mut() = Some(lobby_id);
+                discord.send_lobby_message(lobby_id, b"hello", |_, res| res.unwrap());
+            },
+        );
+
+        discord.run_callbacks().unwrap();
+        discord.run_callbacks().unwrap();
+
+        let lobby_id = created.borrow().unwrap();
+        let current_user_id = discord.current_user().unwrap().id();
+
+        assert_eq!(discord.lobby_metadata(lobby_id, "map").unwrap(), "de_dust2");
+        assert_eq!(
+            backend.sent_lobby_messages(),
+            vec![(lobby_id, b"hello".to_vec())]
+        );
+        assert_eq!(
+            discord.event_handler().as_ref().unwrap().0,
+            vec![(lobby_id, current_user_id, b"hello".to_vec())]
+        );
+
+        backend.add_user(2, "friend", "0002");
+        backend.connect_member(lobby_id, 2);
+        assert_eq!(discord.lobby_member_count(lobby_id).unwrap(), 2);
+
+        // Pending operations are aborted when the instance is dropped
+        let aborted = Rc::new(RefCell::new(None));
+        let aborted_ = aborted.clone();
+
+        discord.delete_lobby(lobby_id, move |_, res| *aborted_.borrow_mut() = Some(res));
+        drop(discord);
+
+        assert_eq!(*aborted.borrow(), Some(Err(Error::TransactionAborted)));
+    }
+
+    #[test]
+    fn events() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        // Events are not buffered by default
+        discord.run_callbacks().unwrap();
+        backend.receive_join("ignored");
+        discord.run_callbacks().unwrap();
+        assert_eq!(discord.drain_events().count(), 0);
+
+        discord.set_event_buffering(true);
+
+        backend.add_user(2, "friend", "0002");
+        let lobby_id = backend.add_lobby(1, LobbyKind::Public, 4);
+        backend.connect_member(lobby_id, 2);
+        backend.receive_lobby_message(lobby_id, 2, b"hello".to_vec());
+        backend.receive_join("secret");
+
+        assert_eq!(
+            discord.poll_events().unwrap().collect::<Vec<_>>(),
+            vec![
+                Event::MemberConnect {
+                    lobby_id,
+                    member_id: 2
+                },
+                Event::LobbyMessage {
+                    lobby_id,
+                    member_id: 2,
+                    data: b"hello".to_vec()
+                },
+                Event::ActivityJoin {
+                    secret: "secret".to_string()
+                },
+            ]
+        );
+
+        assert_eq!(discord.poll_events().unwrap().count(), 0);
+
+        backend.receive_join("discarded");
+        discord.run_callbacks().unwrap();
+        discord.set_event_buffering(false);
+        assert_eq!(discord.drain_events().count(), 0);
+    }
+
+    #[test]
+    fn catch_panics() {
+        #[derive(Default)]
+        struct Joins(u32);
+
+        impl EventHandler for Joins {
+            fn on_activity_join(&mut self, _: &Discord<'_, Self>, secret: &str) {
+                self.0 += 1;
+                assert_ne!(secret, "bad", "bad secret");
             }
         }
+
+        let backend = MockBackend::new();
+        let mut discord = Discord::with_backend(0, &backend).unwrap();
+        *discord.event_handler_mut() = Some(Joins::default());
+        discord.set_catch_panics(true);
+
+        discord.clear_activity(|_, _| panic!("callback"));
+        assert_eq!(
+            discord.run_callbacks(),
+            Err(Error::CallbackPanicked("callback".to_string()))
+        );
+        assert_eq!(discord.run_callbacks(), Ok(()));
+
+        backend.receive_join("bad");
+        backend.receive_join("good");
+        assert!(match discord.run_callbacks() {
+            Err(Error::CallbackPanicked(message)) => message.contains("bad secret"),
+            _ => false,
+        });
+
+        // The event handler survived and received both events
+        assert_eq!(discord.event_handler().as_ref().unwrap().0, 2);
     }
 
-    let mut discord = Discord::mock();
-    *discord.event_handler_mut() = Some(E);
+    #[test]
+    fn error_context() {
+        use std::error::Error as _;
+
+        let mut discord = Discord::<()>::mock();
 
-    discord.fetch_user_achievements(|discord, _res| {
-        discord.set_user_achievement(0, 99, |discord, _res| {
-            for a in discord.iter_user_achievements() {
-                let a = a.unwrap();
-                eprintln!(
-                    "in fetch in set {}: {}%",
-                    a.achievement_id(),
-                    a.percent_complete()
+        // Errors are not wrapped by default
+        assert_eq!(discord.lobby_metadata(42, "map"), Err(Error::NotFound));
+
+        discord.set_error_context(true);
+
+        let error = discord.lobby_metadata(42, "map").unwrap_err();
+        assert_eq!(error.kind(), &Error::NotFound);
+        assert_eq!(
+            error.to_string(),
+            "lobbies::lobby_metadata(lobby_id: 42, key: map) failed"
+        );
+        assert_eq!(error.source().unwrap().to_string(), "not found");
+
+        let deleted = Rc::new(RefCell::new(None));
+        let deleted_ = deleted.clone();
+
+        discord.delete_lobby(42, move |_, res| *deleted_.borrow_mut() = Some(res));
+        discord.run_callbacks().unwrap();
+
+        let deleted = deleted.borrow_mut().take();
+
+        match deleted {
+            Some(Err(Error::Context(context))) => {
+                assert_eq!(context.error(), &Error::NotFound);
+                assert_eq!(
+                    (context.manager(), context.method()),
+                    ("lobbies", "delete_lobby")
                 );
+                assert_eq!(
+                    context.arguments(),
+                    &[("lobby_id".to_string(), "42".to_string())]
+                );
+                assert!(context.issued_at().is_some());
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn sdk_versions() {
+        let backend = MockBackend::new();
+        backend.set_sdk_version(crate::sdk_versions().core() + 1);
+
+        let error = match Discord::<()>::with_backend(0, &backend) {
+            Err(error) => error,
+            Ok(_) => panic!("created an instance with the wrong SDK version"),
+        };
+
+        assert_eq!(
+            error,
+            Error::InvalidVersion {
+                expected: crate::sdk_versions(),
+                actual: None,
             }
+        );
+
+        backend.set_sdk_version(crate::sdk_versions().core());
+        assert!(Discord::<()>::with_backend(0, &backend).is_ok());
+    }
+
+    #[test]
+    fn futures() {
+        let backend = MockBackend::new();
+        backend.add_user(2, "friend", "0002");
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let lobby = discord
+            .run_until(|futures| futures.create_lobby(LobbyTransaction::new().capacity(4)))
+            .unwrap()
+            .unwrap();
+        let owner = discord
+            .run_until(|futures| futures.user(lobby.owner_id()))
+            .unwrap()
+            .unwrap();
+        discord
+            .run_until(|futures| {
+                futures.update_activity(Activity::empty().with_state(owner.username()))
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lobby.capacity(), 4);
+        assert_eq!(owner.username(), "mock");
+        assert_eq!(backend.activity().unwrap().state(), "mock");
+
+        let friend = discord.futures().user(2);
+        assert!(!friend.is_resolved());
+        discord.run_callbacks().unwrap();
+        assert!(friend.is_resolved());
+
+        // Pending futures are aborted when the instance is dropped
+        let aborted = discord.futures().delete_lobby(lobby.id());
+        drop(discord);
+        assert!(aborted.is_resolved());
+
+        let mut discord = Discord::<()>::mock();
+        assert_eq!(
+            discord.run_until(|_| friend).unwrap().unwrap().username(),
+            "friend"
+        );
+        assert_eq!(
+            discord.run_until(|_| aborted).unwrap(),
+            Err(Error::TransactionAborted)
+        );
+    }
+
+    #[test]
+    fn retry() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let mut policy = RetryPolicy::new();
+        policy
+            .with_backoff(Duration::from_millis(0), Duration::from_secs(60))
+            .with_retryable(vec![Error::NotFound]);
+
+        assert_eq!(RetryPolicy::new().delay(4), Duration::from_millis(500) * 4);
+
+        let results = Rc::new(RefCell::new(Vec::new()));
+        let results_ = results.clone();
+
+        discord.retry(&policy).user(2, move |_, res| {
+            results_
+                .borrow_mut()
+                .push(res.map(|user| user.username().to_string()))
+        });
+
+        // First attempt fails, the retry is sent right away as the delay is zero
+        discord.run_callbacks().unwrap();
+        assert!(results.borrow().is_empty());
+
+        backend.add_user(2, "friend", "0002");
+        discord.run_callbacks().unwrap();
+        assert_eq!(*results.borrow(), vec![Ok("friend".to_string())]);
+
+        // Gives up after the last attempt
+        let results_ = results.clone();
+        discord.retry(&policy).user(3, move |_, res| {
+            results_.borrow_mut().push(res.map(|_| String::new()))
+        });
+
+        for _ in 0..5 {
+            discord.run_callbacks().unwrap();
+        }
+
+        assert_eq!(results.borrow()[1..], [Err(Error::NotFound)]);
+
+        // Waiting retries are aborted when the instance is dropped
+        policy.with_backoff(Duration::from_secs(60), Duration::from_secs(60));
+
+        let results_ = results.clone();
+        discord.retry(&policy).user(3, move |discord, res| {
+            results_.borrow_mut().push(res.map(|_| String::new()));
+
+            // The instance can still be used by aborted callbacks
+            results_.borrow_mut().push(
+                discord
+                    .current_user()
+                    .map(|user| user.username().to_string()),
+            );
+        });
+
+        discord.run_callbacks().unwrap();
+        assert_eq!(results.borrow().len(), 2);
+
+        let username = discord.current_user().unwrap().username().to_string();
+        drop(discord);
+        assert_eq!(
+            results.borrow()[2..],
+            [Err(Error::TransactionAborted), Ok(username)]
+        );
+    }
+
+    #[test]
+    fn state_mirror() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+        discord.set_event_buffering(true);
+
+        backend.add_user(2, "friend", "0002");
+        let lobby_id = backend.add_lobby(2, LobbyKind::Public, 4);
+        backend.set_lobby_metadata(lobby_id, "map", "de_dust2");
+        discord.run_callbacks().unwrap();
+        discord.drain_events().for_each(drop);
+
+        let mut mirror = StateMirror::new();
+        let changes = mirror.track_lobby(&discord, lobby_id).unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            changes[1],
+            StateChange::LobbyMetadata {
+                lobby_id,
+                key: "map".to_string(),
+                old: None,
+                new: Some("de_dust2".to_string()),
+            }
+        );
+
+        backend.set_member_metadata(lobby_id, 2, "ready", "0");
+        backend.set_member_metadata(lobby_id, 2, "ready", "1");
+        backend.set_relationship(
+            2,
+            RelationshipKind::Friend,
+            Status::Online,
+            &Activity::empty(),
+        );
+
+        let events = discord.poll_events().unwrap().collect::<Vec<_>>();
+        let mut changes = events
+            .iter()
+            .flat_map(|event| mirror.update(&discord, event))
+            .collect::<Vec<_>>();
+
+        backend.disconnect_member(lobby_id, 2);
+
+        let events = discord.poll_events().unwrap().collect::<Vec<_>>();
+        changes.extend(
+            events
+                .iter()
+                .flat_map(|event| mirror.update(&discord, event)),
+        );
+
+        // Both updates are read after the second one was applied
+        assert_eq!(
+            changes[0],
+            StateChange::MemberMetadata {
+                lobby_id,
+                user_id: 2,
+                key: "ready".to_string(),
+                old: None,
+                new: Some("1".to_string()),
+            }
+        );
+        assert!(match &changes[1] {
+            StateChange::Relationship {
+                user_id: 2,
+                old: None,
+                new: Some(relationship),
+            } => {
+                relationship.kind() == RelationshipKind::Friend
+            }
+            _ => false,
+        });
+        assert_eq!(
+            changes[2],
+            StateChange::Member {
+                lobby_id,
+                user_id: 2,
+                connected: false,
+            }
+        );
+        assert_eq!(changes.len(), 3);
+
+        assert_eq!(mirror.relationships().count(), 1);
+        assert_eq!(mirror.lobby(lobby_id).unwrap().member_ids().count(), 0);
+        assert_eq!(
+            mirror
+                .lobby(lobby_id)
+                .unwrap()
+                .metadata()
+                .get("map")
+                .unwrap(),
+            "de_dust2"
+        );
+
+        // Syncing goes through the relationship filter, and leaves it as is
+        discord.filter_relationships(|_| false);
+        mirror.sync(&discord).unwrap();
+        assert_eq!(mirror.relationships().count(), 0);
+        assert_eq!(discord.relationship_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn driver() {
+        let driver =
+            DiscordDriver::<()>::spawn(Duration::from_millis(1), || Ok(Discord::mock())).unwrap();
+        let handle = driver.handle();
+
+        let worker = thread::spawn(move || {
+            let lobby = handle
+                .call_future(|discord| {
+                    discord.futures().create_lobby(
+                        LobbyTransaction::new()
+                            .capacity(2)
+                            .add_metadata("map".into(), "de_dust2".into()),
+                    )
+                })
+                .wait()
+                .unwrap();
+
+            handle
+                .call(move |discord| discord.lobby_metadata(lobby.id(), "map"))
+                .wait()
+                .unwrap()
+        });
+
+        assert_eq!(worker.join().unwrap().unwrap(), "de_dust2");
+
+        let handle = driver.handle();
+        driver.shutdown().unwrap();
+        assert_eq!(handle.call(|_| ()).wait(), Err(Error::NotRunning));
+    }
+
+    #[test]
+    fn scheduler() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+        let lobby_id = backend.add_lobby(1, LobbyKind::Public, 4);
+
+        let mut scheduler = Scheduler::new();
+
+        let activities = (0..7)
+            .map(|n| {
+                scheduler.update_activity(&discord, Activity::empty().with_state(&n.to_string()))
+            })
+            .collect::<Vec<_>>();
+        let messages = (0..12)
+            .map(|n| scheduler.send_lobby_message(&discord, lobby_id, vec![n]))
+            .collect::<Vec<_>>();
+
+        // The last 2 activities are coalesced
+        assert_eq!(scheduler.queue_depth(), 1 + 2);
+
+        scheduler.flush(&discord);
+        discord.run_callbacks().unwrap();
+
+        assert_eq!(backend.activity().unwrap().state(), "4");
+        assert_eq!(backend.sent_lobby_messages().len(), 10);
+        assert!(activities[4].is_resolved() && !activities[5].is_resolved());
+        assert!(messages[9].is_resolved() && !messages[10].is_resolved());
+
+        drop(scheduler);
+        assert_eq!(
+            activities.into_iter().last().unwrap().wait(),
+            Err(Error::TransactionAborted)
+        );
+    }
+
+    #[test]
+    fn invite_inbox() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+        discord.set_event_buffering(true);
+
+        backend.add_user(2, "friend", "0002");
+        backend.add_user(3, "stranger", "0003");
+        backend.set_relationship(
+            2,
+            RelationshipKind::Friend,
+            Status::Online,
+            &Activity::empty(),
+        );
+
+        let mut inbox = InviteInbox::new();
+        inbox.with_friend_reply(Some(RequestReply::Yes));
+
+        backend.receive_join_request(2);
+        backend.receive_join_request(3);
+        backend.receive_join_request(3);
+        backend.receive_invite(Action::Spectate, 3, &Activity::empty());
+
+        let events = discord.poll_events().unwrap().collect::<Vec<_>>();
+        for event in &events {
+            inbox.update(&discord, event);
+        }
+
+        let pending = inbox.pending().collect::<Vec<_>>();
+        assert_eq!(pending.len(), 2);
+        assert!(pending[0].is_join_request());
+        assert_eq!(pending[1].action(), Some(Action::Spectate));
+        assert!(pending.iter().all(|entry| entry.user().id() == 3));
+
+        assert!(inbox.answer_invite(&discord, 2, true).is_none());
+        let reply = inbox
+            .answer_join_request(&discord, 3, RequestReply::No)
+            .unwrap();
+        let accept = inbox.answer_invite(&discord, 3, true).unwrap();
+        discord.run_callbacks().unwrap();
+
+        assert_eq!(reply.try_take(), Some(Ok(())));
+        assert_eq!(accept.try_take(), Some(Ok(())));
+        assert_eq!(
+            backend.sent_request_replies(),
+            vec![(2, RequestReply::Yes), (3, RequestReply::No)]
+        );
+        assert_eq!(backend.accepted_invites(), vec![3]);
+        assert!(inbox.pending().next().is_none());
+
+        // Entries expire
+        inbox.with_expiry(Duration::from_secs(0));
+        backend.receive_join_request(3);
+        discord.run_callbacks().unwrap();
+        for event in discord.drain_events().collect::<Vec<_>>() {
+            inbox.update(&discord, &event);
+        }
+        assert!(inbox.pending().next().is_none());
+        assert!(inbox
+            .answer_join_request(&discord, 3, RequestReply::Yes)
+            .is_none());
+    }
+
+    #[test]
+    fn lobby_search() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let lobbies = [
+            ("ranked", "eu", 4),
+            ("ranked", "eu", 1),
+            ("ranked", "us", 4),
+            ("casual", "eu", 4),
+            ("ranked", "eu", 2),
+        ]
+        .iter()
+        .map(|&(mode, region, capacity)| {
+            let lobby_id = backend.add_lobby(2, LobbyKind::Public, capacity);
+            backend.set_lobby_metadata(lobby_id, "mode", mode);
+            backend.set_lobby_metadata(lobby_id, "region", region);
+            lobby_id
+        })
+        .collect::<Vec<_>>();
+
+        let mut query = SearchQuery::new();
+        query
+            .filter(
+                "metadata.mode".into(),
+                Comparison::Equal,
+                "ranked".into(),
+                Cast::String,
+            )
+            .filter(
+                "metadata.region".into(),
+                Comparison::Equal,
+                "eu".into(),
+                Cast::String,
+            )
+            .filter(
+                "slots".into(),
+                Comparison::GreaterThan,
+                "0".into(),
+                Cast::Number,
+            )
+            .sort("slots".into(), "1".into(), Cast::Number);
+
+        discord.lobby_search(&query, |_, result| result.unwrap());
+        discord.run_callbacks().unwrap();
+
+        let found = (0..discord.lobby_count())
+            .map(|index| discord.lobby_id_at(index).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, vec![lobbies[4], lobbies[0]]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn typed_metadata() {
+        use crate::MetadataError;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Mode {
+            Casual,
+            Ranked,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct LobbyInfo {
+            mode: Mode,
+            round: u32,
+            password: Option<String>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct MemberInfo {
+            #[allow(dead_code)]
+            ready: bool,
+        }
+
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let lobby_id = discord
+            .run_until(|futures| futures.create_lobby(LobbyTransaction::new().capacity(4)))
+            .unwrap()
+            .unwrap()
+            .id();
+        let user_id = discord.current_user().unwrap().id();
+
+        let mut info = LobbyInfo {
+            mode: Mode::Ranked,
+            round: 3,
+            password: Some("hunter2".to_string()),
+        };
+
+        discord
+            .update_lobby_typed(lobby_id, &info, |_, result| result.unwrap())
+            .unwrap();
+        discord.run_callbacks().unwrap();
+
+        assert_eq!(discord.lobby_metadata(lobby_id, "round").unwrap(), "3");
+        assert_eq!(
+            discord.lobby_metadata_typed::<LobbyInfo>(lobby_id),
+            Ok(info)
+        );
+
+        info = LobbyInfo {
+            mode: Mode::Casual,
+            round: 4,
+            password: None,
+        };
+
+        discord
+            .update_lobby_typed(lobby_id, &info, |_, result| result.unwrap())
+            .unwrap();
+        discord.run_callbacks().unwrap();
+
+        assert_eq!(
+            discord.lobby_metadata_typed::<LobbyInfo>(lobby_id),
+            Ok(info)
+        );
+
+        backend.set_lobby_metadata(lobby_id, "round", "last");
+        assert_eq!(
+            discord.lobby_metadata_typed::<LobbyInfo>(lobby_id),
+            Err(MetadataError::InvalidValue {
+                key: "round".to_string(),
+                value: "last".to_string(),
+                message: "invalid digit found in string".to_string(),
+            })
+        );
+
+        assert_eq!(
+            discord
+                .lobby_member_metadata_typed::<MemberInfo>(lobby_id, user_id)
+                .unwrap_err(),
+            MetadataError::MissingKey("ready".to_string())
+        );
+        assert_eq!(
+            discord.update_member_typed(lobby_id, user_id, &vec![true], |_, _| {}),
+            Err(MetadataError::Unsupported("sequence"))
+        );
+    }
+
+    #[test]
+    fn lobby_members() {
+        let backend = MockBackend::new();
+        let discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        backend.add_user(2, "owner", "0002");
+        backend.add_user(3, "member", "0003");
+        let lobby_id = backend.add_lobby(2, LobbyKind::Public, 4);
+        backend.connect_member(lobby_id, 3);
+        backend.set_member_metadata(lobby_id, 3, "ready", "1");
+
+        assert_eq!(
+            discord.lobby_member_user(lobby_id, 3).unwrap().username(),
+            "member"
+        );
+        assert_eq!(
+            discord.lobby_member_user(lobby_id, 4).unwrap_err(),
+            Error::NotFound
+        );
+
+        let members = discord
+            .iter_lobby_members(lobby_id)
+            .unwrap()
+            .map(|member| {
+                let (user, metadata) = member.unwrap();
+                (user.username().to_string(), metadata.get("ready").cloned())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            members,
+            vec![
+                ("owner".to_string(), None),
+                ("member".to_string(), Some("1".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn reconcile_lobby() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let lobby_id = discord
+            .run_until(|futures| {
+                futures.create_lobby(
+                    LobbyTransaction::new()
+                        .capacity(4)
+                        .add_metadata("map".into(), "de_dust2".into())
+                        .add_metadata("password".into(), "hunter2".into()),
+                )
+            })
+            .unwrap()
+            .unwrap()
+            .id();
+
+        let mut spec = LobbySpec::new();
+        spec.capacity(8)
+            .locked(true)
+            .add_metadata("map".into(), "de_dust2".into())
+            .add_metadata("mode".into(), "ranked".into());
+
+        let results = Rc::new(RefCell::new(Vec::new()));
+
+        for _ in 0..2 {
+            let results = results.clone();
+            discord.reconcile_lobby(lobby_id, &spec, move |_, result| {
+                results.borrow_mut().push(result)
+            });
+            discord.run_callbacks().unwrap();
+        }
+
+        assert_eq!(*results.borrow(), vec![Ok(true), Ok(false)]);
+
+        let lobby = discord.lobby(lobby_id).unwrap();
+        assert_eq!(lobby.capacity(), 8);
+        assert!(lobby.locked());
+        assert_eq!(lobby.kind(), LobbyKind::Private);
+
+        let metadata = discord
+            .iter_lobby_metadata(lobby_id)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            metadata,
+            vec![
+                ("map".to_string(), "de_dust2".to_string()),
+                ("mode".to_string(), "ranked".to_string()),
+            ]
+        );
+
+        discord.reconcile_lobby(42, &spec, |_, result| {
+            assert_eq!(result, Err(Error::NotFound))
         });
-    });
+    }
+
+    #[test]
+    fn presence_manager() {
+        let backend = MockBackend::new();
+        let mut discord = Discord::<()>::with_backend(0, &backend).unwrap();
+
+        let mut presence = PresenceManager::new();
+        presence.with_debounce(Duration::from_secs(0));
+
+        presence.set_activity(Activity::empty().with_state("Menu"));
+        presence.flush(&discord);
+
+        // The same activity is not sent again while in flight
+        presence.set_activity(Activity::empty().with_state("Menu"));
+        assert_eq!(presence.pending().unwrap().state(), "Menu");
+        assert!(presence.confirmed().is_none());
 
-    for _ in 0..100 {
         discord.run_callbacks().unwrap();
+        presence.flush(&discord);
+
+        assert_eq!(presence.confirmed().unwrap().state(), "Menu");
+        assert!(presence.pending().is_none());
+
+        // Nor once confirmed, even if its buffers differ
+        let mut activity = Activity::empty();
+        activity.with_state("In Game").with_state("Menu");
+        presence.set_activity(&activity);
+        assert!(presence.pending().is_none());
+
+        // Changes are debounced
+        presence.with_debounce(Duration::from_secs(60));
+        presence.set_activity(Activity::empty().with_state("In Game"));
+        presence.flush(&discord);
+        discord.run_callbacks().unwrap();
+
+        assert_eq!(backend.activity().unwrap().state(), "Menu");
+        assert_eq!(presence.pending().unwrap().state(), "In Game");
+
+        // An activity changing faster than the debounce delay is still sent
+        presence.with_debounce(Duration::from_secs(1));
+
+        for score in 0..4 {
+            presence.set_activity(Activity::empty().with_state(&format!("Score {}", score)));
+            presence.flush(&discord);
+            discord.run_callbacks().unwrap();
+            thread::sleep(Duration::from_millis(500));
+        }
+
+        assert!(backend.activity().unwrap().state().starts_with("Score"));
     }
 
-    discord.fetch_user_achievements(|discord, _res| {
-        discord.set_user_achievement(0, 99, |_discord, _res| {});
-    });
+    #[test]
+    fn supervisor() {
+        let available = Rc::new(Cell::new(true));
+        let backends = Rc::new(RefCell::new(Vec::new()));
+
+        let mut supervisor = Supervisor::<()>::with_create({
+            let available = available.clone();
+            let backends = backends.clone();
+
+            move || {
+                if !available.get() {
+                    return Err(Error::NotRunning);
+                }
+
+                let backend = MockBackend::new();
+                backends.borrow_mut().push(backend.clone());
+                Discord::with_backend(0, &backend)
+            }
+        });
+        supervisor.set_backoff(Duration::from_secs(0), Duration::from_secs(0));
+
+        assert_eq!(supervisor.state(), ConnectionState::Connected);
+        supervisor
+            .register_launch_command("game --discord")
+            .unwrap();
+        let update = supervisor.update_activity(Activity::empty().with_state("In menu"));
+        assert_eq!(supervisor.run_callbacks(), Ok(None));
+        assert_eq!(update.wait(), Ok(()));
+
+        backends.borrow()[0].set_running(false);
+        available.set(false);
+
+        assert_eq!(
+            supervisor.run_callbacks(),
+            Ok(Some(ConnectionState::Disconnected))
+        );
+        assert!(supervisor.discord().is_none());
+        assert_eq!(
+            supervisor.run_callbacks(),
+            Ok(Some(ConnectionState::Reconnecting))
+        );
+
+        available.set(true);
+
+        assert_eq!(
+            supervisor.run_callbacks(),
+            Ok(Some(ConnectionState::Connected))
+        );
+        assert_eq!(supervisor.run_callbacks(), Ok(None));
+
+        let backend = &backends.borrow()[1];
+        assert_eq!(backend.activity().unwrap().state(), "In menu");
+        assert_eq!(backend.launch_command().unwrap(), "game --discord");
+    }
+
+    #[test]
+    fn miri_tests() {
+        struct E;
+
+        impl EventHandler for E {
+            fn on_user_achievement_update(
+                &mut self,
+                discord: &Discord<'_, Self>,
+                user_achievement: &UserAchievement,
+            ) {
+                for a in discord.iter_user_achievements() {
+                    let a = a.unwrap();
+                    eprintln!(
+                        "in event_handler {}: {}%",
+                        a.achievement_id(),
+                        a.percent_complete()
+                    );
+                }
+
+                if user_achievement.percent_complete() == 99 {
+                    discord.set_user_achievement(
+                        user_achievement.achievement_id(),
+                        100,
+                        |discord, _res| {
+                            for a in discord.iter_user_achievements() {
+                                let a = a.unwrap();
+                                eprintln!(
+                                    "in event_handler in set {}: {}%",
+                                    a.achievement_id(),
+                                    a.percent_complete()
+                                );
+                            }
+                        },
+                    );
+                }
+            }
+        }
+
+        let backend = super::MockBackend::new();
+
+        for achievement_id in 0..10 {
+            backend.add_user_achievement(achievement_id, 0);
+        }
+
+        let mut discord = Discord::with_backend(0, &backend).unwrap();
+        *discord.event_handler_mut() = Some(E);
+
+        discord.fetch_user_achievements(|discord, _res| {
+            discord.set_user_achievement(0, 99, |discord, _res| {
+                for a in discord.iter_user_achievements() {
+                    let a = a.unwrap();
+                    eprintln!(
+                        "in fetch in set {}: {}%",
+                        a.achievement_id(),
+                        a.percent_complete()
+                    );
+                }
+            });
+        });
+
+        for _ in 0..100 {
+            discord.run_callbacks().unwrap();
+        }
+
+        discord.fetch_user_achievements(|discord, _res| {
+            discord.set_user_achievement(0, 99, |_discord, _res| {});
+        });
+    }
 }
@@ -0,0 +1,286 @@
+use super::{
+    ffi::{LobbyMemberTransaction, LobbyTransaction, SearchQuery},
+    MockBackend,
+};
+use crate::{
+    sys, utils, Action, LobbyID, NetworkChannelID, NetworkPeerID, RequestReply, Snowflake,
+    UnixTimestamp, UserID,
+};
+use std::collections::{BTreeMap, VecDeque};
+
+pub(crate) type Task = Box<dyn FnOnce(&MockBackend, bool)>;
+
+pub(crate) struct State {
+    pub(crate) params: Option<sys::DiscordCreateParams>,
+    pub(crate) running: bool,
+    pub(crate) sdk_version: sys::DiscordVersion,
+    pub(crate) next_id: Snowflake,
+    pub(crate) queue: VecDeque<Task>,
+
+    // Applications
+    pub(crate) locale: String,
+    pub(crate) branch: String,
+    pub(crate) oauth2_token: sys::DiscordOAuth2Token,
+    pub(crate) app_ticket: String,
+
+    // Users
+    pub(crate) current_user: sys::DiscordUser,
+    pub(crate) premium_kind: sys::EDiscordPremiumType,
+    pub(crate) user_flags: sys::EDiscordUserFlag,
+    pub(crate) users: Vec<sys::DiscordUser>,
+
+    // Images
+    pub(crate) images: Vec<Image>,
+
+    // Activities
+    pub(crate) activity: Option<sys::DiscordActivity>,
+    pub(crate) launch_command: Option<String>,
+    pub(crate) steam_id: Option<u32>,
+    pub(crate) sent_invites: Vec<(UserID, Action, String)>,
+    pub(crate) sent_request_replies: Vec<(UserID, RequestReply)>,
+    pub(crate) accepted_invites: Vec<UserID>,
+
+    // Relationships
+    pub(crate) relationships: Vec<sys::DiscordRelationship>,
+    pub(crate) filtered_relationships: Option<Vec<sys::DiscordRelationship>>,
+
+    // Lobbies
+    pub(crate) lobbies: Vec<Lobby>,
+    pub(crate) search_results: Vec<LobbyID>,
+    pub(crate) sent_lobby_messages: Vec<(LobbyID, Vec<u8>)>,
+    pub(crate) sent_lobby_network_messages: Vec<(LobbyID, UserID, NetworkChannelID, Vec<u8>)>,
+    pub(crate) lobby_transactions: Vec<*mut LobbyTransaction>,
+    pub(crate) member_transactions: Vec<*mut LobbyMemberTransaction>,
+    pub(crate) search_queries: Vec<*mut SearchQuery>,
+
+    // Networking
+    pub(crate) peer_id: NetworkPeerID,
+    pub(crate) route: String,
+    pub(crate) peers: BTreeMap<NetworkPeerID, String>,
+    pub(crate) channels: BTreeMap<(NetworkPeerID, NetworkChannelID), bool>,
+    pub(crate) sent_network_messages: Vec<(NetworkPeerID, NetworkChannelID, Vec<u8>)>,
+
+    // Overlay
+    pub(crate) overlay_enabled: bool,
+    pub(crate) overlay_locked: bool,
+
+    // Storage
+    pub(crate) folder_path: String,
+    pub(crate) files: BTreeMap<String, File>,
+
+    // Store
+    pub(crate) skus: Vec<sys::DiscordSku>,
+    pub(crate) entitlements: Vec<sys::DiscordEntitlement>,
+
+    // Voice
+    pub(crate) input_mode: sys::DiscordInputMode,
+    pub(crate) self_mute: bool,
+    pub(crate) self_deaf: bool,
+    pub(crate) local_mutes: BTreeMap<UserID, bool>,
+    pub(crate) local_volumes: BTreeMap<UserID, u8>,
+
+    // Achievements
+    pub(crate) achievements: Vec<sys::DiscordUserAchievement>,
+}
+
+pub(crate) struct Image {
+    pub(crate) handle: sys::DiscordImageHandle,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) data: Vec<u8>,
+}
+
+pub(crate) struct Lobby {
+    pub(crate) lobby: sys::DiscordLobby,
+    pub(crate) metadata: Vec<(String, String)>,
+    pub(crate) members: Vec<Member>,
+}
+
+pub(crate) struct Member {
+    pub(crate) user_id: UserID,
+    pub(crate) metadata: Vec<(String, String)>,
+    pub(crate) network_connected: bool,
+    pub(crate) voice_connected: bool,
+}
+
+pub(crate) struct File {
+    pub(crate) data: Vec<u8>,
+    pub(crate) last_modified: UnixTimestamp,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let mut current_user = sys::DiscordUser {
+            id: 1,
+            ..Default::default()
+        };
+        utils::write_charbuf(&mut current_user.username, "mock");
+        utils::write_charbuf(&mut current_user.discriminator, "0001");
+
+        let mut oauth2_token = sys::DiscordOAuth2Token::default();
+        utils::write_charbuf(&mut oauth2_token.access_token, "mock-access-token");
+        utils::write_charbuf(&mut oauth2_token.scopes, "identify");
+
+        Self {
+            params: None,
+            running: true,
+            sdk_version: sys::DISCORD_VERSION,
+            next_id: 1000,
+            queue: VecDeque::new(),
+
+            locale: "en-US".to_string(),
+            branch: "master".to_string(),
+            oauth2_token,
+            app_ticket: "mock-app-ticket".to_string(),
+
+            current_user,
+            premium_kind: sys::DiscordPremiumType_None,
+            user_flags: 0,
+            users: Vec::new(),
+
+            images: Vec::new(),
+
+            activity: None,
+            launch_command: None,
+            steam_id: None,
+            sent_invites: Vec::new(),
+            sent_request_replies: Vec::new(),
+            accepted_invites: Vec::new(),
+
+            relationships: Vec::new(),
+            filtered_relationships: None,
+
+            lobbies: Vec::new(),
+            search_results: Vec::new(),
+            sent_lobby_messages: Vec::new(),
+            sent_lobby_network_messages: Vec::new(),
+            lobby_transactions: Vec::new(),
+            member_transactions: Vec::new(),
+            search_queries: Vec::new(),
+
+            peer_id: 1,
+            route: "mock-route".to_string(),
+            peers: BTreeMap::new(),
+            channels: BTreeMap::new(),
+            sent_network_messages: Vec::new(),
+
+            overlay_enabled: true,
+            overlay_locked: false,
+
+            folder_path: "/mock/storage".to_string(),
+            files: BTreeMap::new(),
+
+            skus: Vec::new(),
+            entitlements: Vec::new(),
+
+            input_mode: sys::DiscordInputMode::default(),
+            self_mute: false,
+            self_deaf: false,
+            local_mutes: BTreeMap::new(),
+            local_volumes: BTreeMap::new(),
+
+            achievements: Vec::new(),
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.free_transactions();
+    }
+}
+
+impl State {
+    pub(crate) fn next_id(&mut self) -> Snowflake {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    pub(crate) fn user(&self, user_id: UserID) -> Option<sys::DiscordUser> {
+        if self.current_user.id == user_id {
+            return Some(self.current_user);
+        }
+
+        self.users.iter().find(|user| user.id == user_id).copied()
+    }
+
+    pub(crate) fn relationships(&self) -> &[sys::DiscordRelationship] {
+        self.filtered_relationships
+            .as_ref()
+            .unwrap_or(&self.relationships)
+    }
+
+    pub(crate) fn lobby(&self, lobby_id: LobbyID) -> Option<&Lobby> {
+        self.lobbies.iter().find(|lobby| lobby.lobby.id == lobby_id)
+    }
+
+    pub(crate) fn lobby_mut(&mut self, lobby_id: LobbyID) -> Option<&mut Lobby> {
+        self.lobbies
+            .iter_mut()
+            .find(|lobby| lobby.lobby.id == lobby_id)
+    }
+
+    pub(crate) fn free_transactions(&mut self) {
+        unsafe {
+            for ptr in self.lobby_transactions.drain(..) {
+                drop(Box::from_raw(ptr));
+            }
+
+            for ptr in self.member_transactions.drain(..) {
+                drop(Box::from_raw(ptr));
+            }
+
+            for ptr in self.search_queries.drain(..) {
+                drop(Box::from_raw(ptr));
+            }
+        }
+    }
+}
+
+impl Lobby {
+    pub(crate) fn member(&self, user_id: UserID) -> Option<&Member> {
+        self.members.iter().find(|member| member.user_id == user_id)
+    }
+
+    pub(crate) fn member_mut(&mut self, user_id: UserID) -> Option<&mut Member> {
+        self.members
+            .iter_mut()
+            .find(|member| member.user_id == user_id)
+    }
+}
+
+impl Member {
+    pub(crate) fn new(user_id: UserID) -> Self {
+        Self {
+            user_id,
+            metadata: Vec::new(),
+            network_connected: false,
+            voice_connected: false,
+        }
+    }
+}
+
+pub(crate) fn get_metadata<'a>(metadata: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    metadata
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+pub(crate) fn set_metadata(metadata: &mut Vec<(String, String)>, key: &str, value: &str) {
+    match metadata.iter_mut().find(|(k, _)| k == key) {
+        Some((_, v)) => *v = value.to_string(),
+        None => metadata.push((key.to_string(), value.to_string())),
+    }
+}
+
+pub(crate) fn delete_metadata(metadata: &mut Vec<(String, String)>, key: &str) {
+    metadata.retain(|(k, _)| k != key)
+}
+
+pub(crate) fn now() -> UnixTimestamp {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as UnixTimestamp)
+        .unwrap_or(0)
+}
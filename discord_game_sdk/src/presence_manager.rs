@@ -0,0 +1,172 @@
+use crate::{
+    discord_future::{self, DiscordFuture},
+    Activity, Discord,
+};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Sends activity updates only when the activity changed
+///
+/// Remembers the last activity Discord confirmed, and compares new activities to it by the
+/// values returned by their getters. [`set_activity`] only records the activity, [`flush`] sends
+/// the latest one once the debounce delay, 1 second by default, elapsed since the first change
+/// that wasn't sent, and no other update is in flight. An activity that keeps changing, such as
+/// a score or a timer, is sent at most once per delay. Setting an empty activity clears the
+/// activity instead.
+///
+/// [`flush`] should be called as often as [`run_callbacks`].
+/// When an update fails, the error is logged and the confirmed activity is kept,
+/// setting the same activity again sends it again.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+/// let mut presence = PresenceManager::new();
+///
+/// loop {
+///     // Only sent when the state of the game changes
+///     presence.set_activity(Activity::empty().with_state("In Game"));
+///
+///     presence.flush(&discord);
+///     discord.run_callbacks()?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`flush`]: #method.flush
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`set_activity`]: #method.set_activity
+pub struct PresenceManager {
+    debounce: Duration,
+    confirmed: Option<Activity>,
+    pending: Option<(Activity, Instant)>,
+    in_flight: Option<(Activity, DiscordFuture<()>)>,
+}
+
+impl PresenceManager {
+    /// Creates a manager with no confirmed activity, and a debounce delay of 1 second.
+    pub fn new() -> Self {
+        Self {
+            debounce: Duration::from_secs(1),
+            confirmed: None,
+            pending: None,
+            in_flight: None,
+        }
+    }
+
+    /// How long changes are gathered before the latest one is sent
+    pub fn with_debounce(&mut self, value: Duration) -> &mut Self {
+        self.debounce = value;
+        self
+    }
+
+    /// The last activity Discord confirmed, `None` before the first update succeeded
+    pub fn confirmed(&self) -> Option<&Activity> {
+        self.confirmed.as_ref()
+    }
+
+    /// The activity waiting to be sent, or waiting for Discord to confirm it
+    pub fn pending(&self) -> Option<&Activity> {
+        self.pending
+            .as_ref()
+            .map(|(activity, _)| activity)
+            .or_else(|| self.in_flight.as_ref().map(|(activity, _)| activity))
+    }
+
+    /// Records the activity to send, unless it is the same as the latest one.
+    pub fn set_activity(&mut self, activity: &Activity) {
+        if let Some(latest) = self.pending().or_else(|| self.confirmed()) {
+            if same_activity(latest, activity) {
+                return;
+            }
+        }
+
+        // Keeps the time of the first change, so that frequent changes are still sent
+        let changed_at = match &self.pending {
+            Some((_, changed_at)) => *changed_at,
+            None => Instant::now(),
+        };
+
+        self.pending = Some((activity.clone(), changed_at));
+    }
+
+    /// Collects the result of the update in flight, and sends the pending activity
+    /// if the debounce delay elapsed.
+    pub fn flush<E>(&mut self, discord: &Discord<'_, E>) {
+        if let Some(result) = self.in_flight.as_ref().and_then(|(_, f)| f.try_take()) {
+            let (activity, _) = self.in_flight.take().unwrap();
+
+            match result {
+                Ok(()) => self.confirmed = Some(activity),
+                Err(error) => log::warn!("failed to update activity: {}", error),
+            }
+        }
+
+        if self.in_flight.is_some() {
+            return;
+        }
+
+        match &self.pending {
+            Some((_, changed_at)) if changed_at.elapsed() >= self.debounce => {}
+            _ => return,
+        }
+
+        let (activity, _) = self.pending.take().unwrap();
+
+        if let Some(confirmed) = &self.confirmed {
+            if same_activity(confirmed, &activity) {
+                return;
+            }
+        }
+
+        let (resolver, future) = discord_future::channel();
+
+        if activity.is_empty() {
+            discord.clear_activity(|_, result| resolver.resolve(result));
+        } else {
+            discord.update_activity(&activity, |_, result| resolver.resolve(result));
+        }
+
+        self.in_flight = Some((activity, future));
+    }
+}
+
+impl Default for PresenceManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PresenceManager {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("PresenceManager")
+            .field("debounce", &self.debounce)
+            .field("confirmed", &self.confirmed)
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+// `Activity` compares whole buffers, including bytes left after the strings
+fn same_activity(a: &Activity, b: &Activity) -> bool {
+    a.kind() == b.kind()
+        && a.application_id() == b.application_id()
+        && a.name() == b.name()
+        && a.state() == b.state()
+        && a.details() == b.details()
+        && a.start_time() == b.start_time()
+        && a.end_time() == b.end_time()
+        && a.large_image_key() == b.large_image_key()
+        && a.large_image_tooltip() == b.large_image_tooltip()
+        && a.small_image_key() == b.small_image_key()
+        && a.small_image_tooltip() == b.small_image_tooltip()
+        && a.party_id() == b.party_id()
+        && a.party_amount() == b.party_amount()
+        && a.party_capacity() == b.party_capacity()
+        && a.instance() == b.instance()
+        && a.match_secret() == b.match_secret()
+        && a.join_secret() == b.join_secret()
+        && a.spectate_secret() == b.spectate_secret()
+}
@@ -0,0 +1,247 @@
+use crate::{Activity, ActivityBuilder, TemplateError};
+use std::{borrow::Borrow, collections::HashMap, fmt, hash::BuildHasher, hash::Hash};
+
+/// Template rendering an [`Activity`] from the state of the game
+///
+/// Every field is a string where `{name}` is replaced by the value of the variable `name`,
+/// `{{` and `}}` are written as `{` and `}`. Image keys are templates too, to pick assets
+/// from the state, such as `"map_{map}"`.
+///
+/// With the `serde` feature, templates can be loaded from a file, one per state of the game:
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # use std::collections::HashMap;
+/// # #[cfg(feature = "serde")]
+/// # fn example(discord: Discord<'_, ()>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// // {
+/// //     "menu": { "state": "In the menus" },
+/// //     "match": {
+/// //         "state": "{map} — {score_a}:{score_b}",
+/// //         "details": "{mode}",
+/// //         "large_image_key": "map_{map}"
+/// //     }
+/// // }
+/// let templates: HashMap<String, PresenceTemplate> =
+///     serde_json::from_str(&std::fs::read_to_string("presence.json")?)?;
+///
+/// let mut vars = HashMap::new();
+/// vars.insert("map", "de_dust2".to_string());
+/// vars.insert("mode", "Competitive".to_string());
+/// vars.insert("score_a", 7.to_string());
+/// vars.insert("score_b", 5.to_string());
+///
+/// let activity = templates["match"].render(&vars)?;
+/// discord.update_activity(&activity, |_, _| {});
+/// # Ok(()) }
+/// ```
+///
+/// [`Activity`]: struct.Activity.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct PresenceTemplate {
+    state: String,
+    details: String,
+    large_image_key: String,
+    large_image_tooltip: String,
+    small_image_key: String,
+    small_image_tooltip: String,
+}
+
+impl PresenceTemplate {
+    /// Create a new template with empty fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Template of the player's current party status
+    pub fn state(&mut self, value: impl Into<String>) -> &mut Self {
+        self.state = value.into();
+        self
+    }
+
+    /// Template of what the player is currently doing
+    pub fn details(&mut self, value: impl Into<String>) -> &mut Self {
+        self.details = value.into();
+        self
+    }
+
+    /// Template of the key of an asset to display
+    pub fn large_image_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.large_image_key = value.into();
+        self
+    }
+
+    /// Template of the tooltip displayed when hovering over the large image
+    pub fn large_image_tooltip(&mut self, value: impl Into<String>) -> &mut Self {
+        self.large_image_tooltip = value.into();
+        self
+    }
+
+    /// Template of the key of an asset to display
+    pub fn small_image_key(&mut self, value: impl Into<String>) -> &mut Self {
+        self.small_image_key = value.into();
+        self
+    }
+
+    /// Template of the tooltip displayed when hovering over the small image
+    pub fn small_image_tooltip(&mut self, value: impl Into<String>) -> &mut Self {
+        self.small_image_tooltip = value.into();
+        self
+    }
+
+    /// Replaces the placeholders with the variables, and builds the
+    /// [`Activity`](struct.Activity.html) through [`ActivityBuilder`](struct.ActivityBuilder.html).
+    ///
+    /// ## Errors
+    ///
+    /// [`TemplateError`](enum.TemplateError.html) for the first field with invalid syntax or
+    /// an undefined variable, or listing every field that doesn't fit in the SDK's buffers.
+    pub fn render<K, V, S>(&self, vars: &HashMap<K, V, S>) -> Result<Activity, TemplateError>
+    where
+        K: Borrow<str> + Eq + Hash,
+        V: fmt::Display,
+        S: BuildHasher,
+    {
+        let render = |field, template: &str| render(field, template, vars);
+
+        ActivityBuilder::new()
+            .state(render("state", &self.state)?)
+            .details(render("details", &self.details)?)
+            .large_image_key(render("large_image_key", &self.large_image_key)?)
+            .large_image_tooltip(render("large_image_tooltip", &self.large_image_tooltip)?)
+            .small_image_key(render("small_image_key", &self.small_image_key)?)
+            .small_image_tooltip(render("small_image_tooltip", &self.small_image_tooltip)?)
+            .build()
+            .map_err(TemplateError::Activity)
+    }
+}
+
+const BRACES: &[char] = &['{', '}'];
+
+fn render<K, V, S>(
+    field: &'static str,
+    template: &str,
+    vars: &HashMap<K, V, S>,
+) -> Result<String, TemplateError>
+where
+    K: Borrow<str> + Eq + Hash,
+    V: fmt::Display,
+    S: BuildHasher,
+{
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find(BRACES) {
+        let offset = template.len() - rest.len() + start;
+
+        rendered.push_str(&rest[..start]);
+
+        let brace = &rest[start..start + 1];
+        rest = &rest[start + 1..];
+
+        // Escaped brace
+        if rest.starts_with(brace) {
+            rendered.push_str(brace);
+            rest = &rest[1..];
+            continue;
+        }
+
+        if brace == "}" {
+            return Err(TemplateError::InvalidSyntax { field, offset });
+        }
+
+        let end = match rest.find(BRACES) {
+            Some(end) if rest[end..].starts_with('}') && end > 0 => end,
+            _ => return Err(TemplateError::InvalidSyntax { field, offset }),
+        };
+
+        let name = &rest[..end];
+        rest = &rest[end + 1..];
+
+        match vars.get(name) {
+            Some(value) => rendered.push_str(&value.to_string()),
+            None => {
+                return Err(TemplateError::MissingVariable {
+                    field,
+                    name: name.to_string(),
+                })
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InvalidField;
+
+    #[test]
+    fn render() {
+        let mut template = PresenceTemplate::new();
+        template
+            .state("{map} — {score_a}:{score_b}")
+            .details("{{ranked}}")
+            .large_image_key("map_{map}");
+
+        let mut vars = HashMap::new();
+        vars.insert("map", "de_dust2".to_string());
+        vars.insert("score_a", 7.to_string());
+
+        assert_eq!(
+            template.render(&vars),
+            Err(TemplateError::MissingVariable {
+                field: "state",
+                name: "score_b".to_string(),
+            })
+        );
+
+        vars.insert("score_b", 5.to_string());
+
+        let activity = template.render(&vars).unwrap();
+
+        assert_eq!(activity.state(), "de_dust2 — 7:5");
+        assert_eq!(activity.details(), "{ranked}");
+        assert_eq!(activity.large_image_key(), "map_de_dust2");
+
+        vars.insert("map", "a".repeat(200));
+
+        assert_eq!(
+            template
+                .render(&vars)
+                .unwrap_err()
+                .activity_error()
+                .unwrap()
+                .fields(),
+            &[
+                InvalidField::TooLong {
+                    field: "state",
+                    len: 208,
+                    max: 127,
+                },
+                InvalidField::TooLong {
+                    field: "large_image_key",
+                    len: 204,
+                    max: 127,
+                },
+            ]
+        );
+
+        for (invalid, offset) in &[("{map", 0), ("a}", 1), ("{}", 0), ("{a{b}}", 0)] {
+            assert_eq!(
+                PresenceTemplate::new().details(*invalid).render(&vars),
+                Err(TemplateError::InvalidSyntax {
+                    field: "details",
+                    offset: *offset,
+                })
+            );
+        }
+    }
+}
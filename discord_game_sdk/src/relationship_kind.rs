@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/relationships#data-models-relationshiptype-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelationshipKind {
     /// User is blocked
     Blocked,
@@ -34,3 +35,17 @@ impl From<sys::EDiscordRelationshipType> for RelationshipKind {
         }
     }
 }
+
+impl Into<sys::EDiscordRelationshipType> for RelationshipKind {
+    fn into(self) -> sys::EDiscordRelationshipType {
+        match self {
+            Self::Blocked => sys::DiscordRelationshipType_Blocked,
+            Self::Friend => sys::DiscordRelationshipType_Friend,
+            Self::Implicit => sys::DiscordRelationshipType_Implicit,
+            Self::None => sys::DiscordRelationshipType_None,
+            Self::PendingIncoming => sys::DiscordRelationshipType_PendingIncoming,
+            Self::PendingOutgoing => sys::DiscordRelationshipType_PendingOutgoing,
+            Self::Undefined(n) => n,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 /// Network Channel Reliability
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Reliability {
     /// All data will be received
     Reliable,
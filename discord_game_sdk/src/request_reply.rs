@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/activities#data-models-activityjoinrequestreply-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RequestReply {
     /// Accept the request
     Yes,
@@ -15,6 +16,17 @@ pub enum RequestReply {
     Undefined(sys::EDiscordActivityJoinRequestReply),
 }
 
+impl From<sys::EDiscordActivityJoinRequestReply> for RequestReply {
+    fn from(source: sys::EDiscordActivityJoinRequestReply) -> Self {
+        match source {
+            sys::DiscordActivityJoinRequestReply_Yes => Self::Yes,
+            sys::DiscordActivityJoinRequestReply_No => Self::No,
+            sys::DiscordActivityJoinRequestReply_Ignore => Self::Ignore,
+            _ => Self::Undefined(source),
+        }
+    }
+}
+
 impl Into<sys::EDiscordActivityJoinRequestReply> for RequestReply {
     fn into(self) -> sys::EDiscordActivityJoinRequestReply {
         match self {
@@ -0,0 +1,190 @@
+use crate::{Discord, Error, Lobby, LobbyID, Result, RetryPolicy, User, UserID};
+use std::{borrow::Cow, rc::Rc, time::Instant};
+
+/// Variants of the methods taking a `callback` that retry transient failures
+///
+/// Obtained with [`Discord::retry`]. Every method mirrors the method of the same name on
+/// [`Discord`], except it is called again when it fails with an error the [`RetryPolicy`]
+/// considers retryable, until it succeeds or runs out of attempts.
+/// The `callback` is only called once, with the result of the last attempt.
+///
+/// Retries are sent by [`Discord::run_callbacks`] once their delay has elapsed, so the delay is
+/// only as precise as the frequency at which it is called.
+/// Retries that are still waiting when the instance is dropped call their `callback` with
+/// `Err(TransactionAborted)`.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+/// let policy = RetryPolicy::new().with_max_attempts(5).clone();
+///
+/// discord.retry(&policy).fetch_entitlements(|discord, result| match result {
+///     Ok(()) => println!("{} entitlements", discord.entitlement_count()),
+///     Err(error) => eprintln!("failed to fetch entitlements: {}", error),
+/// });
+///
+/// loop {
+///     discord.run_callbacks()?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`Discord`]: struct.Discord.html
+/// [`Discord::retry`]: struct.Discord.html#method.retry
+/// [`Discord::run_callbacks`]: struct.Discord.html#method.run_callbacks
+/// [`RetryPolicy`]: struct.RetryPolicy.html
+pub struct Retry<'a, 'd, E> {
+    pub(crate) discord: &'a Discord<'d, E>,
+    pub(crate) policy: Rc<RetryPolicy>,
+}
+
+impl<E> Clone for Retry<'_, '_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            discord: self.discord,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug> std::fmt::Debug for Retry<'_, '_, E> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Retry")
+            .field("discord", self.discord)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<'d, E: 'd> Retry<'_, 'd, E> {
+    /// [`Discord::fetch_user_achievements`](struct.Discord.html#method.fetch_user_achievements)
+    pub fn fetch_user_achievements(self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
+        self.start(callback, |discord, attempt| {
+            discord.fetch_user_achievements(move |discord, res| attempt.finish(discord, res))
+        })
+    }
+
+    /// [`Discord::connect_lobby`](struct.Discord.html#method.connect_lobby)
+    pub fn connect_lobby<'s>(
+        self,
+        lobby_id: LobbyID,
+        secret: impl Into<Cow<'s, str>>,
+        callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&Lobby>),
+    ) {
+        let secret = secret.into().into_owned();
+
+        self.start(callback, move |discord, attempt| {
+            discord.connect_lobby(lobby_id, secret.as_str(), move |discord, res| {
+                attempt.finish(discord, res)
+            })
+        })
+    }
+
+    /// [`Discord::write_file_async`](struct.Discord.html#method.write_file_async)
+    pub fn write_file_async<'s>(
+        self,
+        filename: impl Into<Cow<'s, str>>,
+        buffer: impl AsRef<[u8]>,
+        callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>),
+    ) {
+        let filename = filename.into().into_owned();
+        let buffer = buffer.as_ref().to_vec();
+
+        self.start(callback, move |discord, attempt| {
+            discord.write_file_async(filename.as_str(), &buffer, move |discord, res| {
+                attempt.finish(discord, res)
+            })
+        })
+    }
+
+    /// [`Discord::fetch_skus`](struct.Discord.html#method.fetch_skus)
+    pub fn fetch_skus(self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
+        self.start(callback, |discord, attempt| {
+            discord.fetch_skus(move |discord, res| attempt.finish(discord, res))
+        })
+    }
+
+    /// [`Discord::fetch_entitlements`](struct.Discord.html#method.fetch_entitlements)
+    pub fn fetch_entitlements(self, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<()>)) {
+        self.start(callback, |discord, attempt| {
+            discord.fetch_entitlements(move |discord, res| attempt.finish(discord, res))
+        })
+    }
+
+    /// [`Discord::user`](struct.Discord.html#method.user)
+    pub fn user(self, user_id: UserID, callback: impl 'd + FnOnce(&Discord<'d, E>, Result<&User>)) {
+        self.start(callback, move |discord, attempt| {
+            discord.user(user_id, move |discord, res| attempt.finish(discord, res))
+        })
+    }
+
+    fn start<T, C>(self, callback: C, operation: impl 'd + Fn(&Discord<'d, E>, Attempt<'d, E, C>))
+    where
+        C: 'd + FnOnce(&Discord<'d, E>, Result<T>),
+    {
+        Attempt {
+            number: 1,
+            policy: self.policy,
+            operation: Rc::new(operation),
+            callback,
+            abort: |discord, callback: C| callback(discord, Err(Error::TransactionAborted)),
+        }
+        .start(self.discord)
+    }
+}
+
+type Operation<'d, E, C> = Rc<dyn 'd + Fn(&Discord<'d, E>, Attempt<'d, E, C>)>;
+
+// One attempt of an operation, passed on to the next one until the callback is called
+struct Attempt<'d, E, C> {
+    number: u32,
+    policy: Rc<RetryPolicy>,
+    operation: Operation<'d, E, C>,
+    callback: C,
+    // Calls `callback` with `Err(TransactionAborted)`
+    abort: fn(&Discord<'d, E>, C),
+}
+
+impl<'d, E: 'd, C: 'd> Attempt<'d, E, C> {
+    fn start(self, discord: &Discord<'d, E>) {
+        let operation = self.operation.clone();
+        operation(discord, self)
+    }
+
+    // Calls `callback` with `res`, unless the operation should be attempted again
+    fn finish<T>(mut self, discord: &Discord<'d, E>, res: Result<T>)
+    where
+        C: FnOnce(&Discord<'d, E>, Result<T>),
+    {
+        let error = match &res {
+            Err(error)
+                if self.number < self.policy.max_attempts() && self.policy.is_retryable(error) =>
+            {
+                error
+            }
+            _ => return (self.callback)(discord, res),
+        };
+
+        self.number += 1;
+        let delay = self.policy.delay(self.number);
+
+        log::debug!(
+            "retrying in {:?}, attempt {} of {}: {}",
+            delay,
+            self.number,
+            self.policy.max_attempts(),
+            error
+        );
+
+        discord.defer_retry(
+            Instant::now() + delay,
+            Box::new(move |discord, run| {
+                if run {
+                    self.start(discord)
+                } else {
+                    (self.abort)(discord, self.callback)
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::Error;
+use std::time::Duration;
+
+/// When and how often [`Retry`](struct.Retry.html) calls an operation again
+///
+/// By default, an operation is attempted up to 3 times, waiting 500 milliseconds before the
+/// first retry and doubling the delay after each attempt, up to 10 seconds.
+///
+/// The retryable errors are, by default, the transient ones:
+/// `ServiceUnavailable`, `LockFailed`, `Internal`, `TransactionAborted`, `RateLimited` and
+/// `NotFetched`.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new()
+///     .with_max_attempts(5)
+///     .with_backoff(Duration::from_secs(1), Duration::from_secs(30))
+///     .with_retryable(vec![Error::RateLimited])
+///     .clone();
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    retryable: Vec<Error>,
+}
+
+impl RetryPolicy {
+    /// Creates the default policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of attempts, including the first one
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay before the first retry
+    pub fn backoff(&self) -> Duration {
+        self.backoff
+    }
+
+    /// The maximum delay between two attempts
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// The errors that cause the operation to be attempted again
+    pub fn retryable(&self) -> &[Error] {
+        &self.retryable
+    }
+
+    /// Whether an operation that failed with `error` should be attempted again,
+    /// ignoring its [`ErrorContext`](struct.ErrorContext.html)
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        self.retryable.contains(error.kind())
+    }
+
+    /// The delay before attempt number `attempt`, starting at 2 for the first retry
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let mut delay = self.backoff;
+
+        for _ in 2..attempt {
+            if delay >= self.max_backoff {
+                break;
+            }
+
+            delay *= 2;
+        }
+
+        delay.min(self.max_backoff)
+    }
+
+    /// The maximum number of attempts, including the first one, at least 1
+    pub fn with_max_attempts(&mut self, value: u32) -> &mut Self {
+        self.max_attempts = value.max(1);
+        self
+    }
+
+    /// The delay before the first retry, doubled after each attempt up to `max`
+    pub fn with_backoff(&mut self, initial: Duration, max: Duration) -> &mut Self {
+        self.backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// The errors that cause the operation to be attempted again
+    pub fn with_retryable(&mut self, value: Vec<Error>) -> &mut Self {
+        self.retryable = value;
+        self
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            retryable: vec![
+                Error::ServiceUnavailable,
+                Error::LockFailed,
+                Error::Internal,
+                Error::TransactionAborted,
+                Error::RateLimited,
+                Error::NotFetched,
+            ],
+        }
+    }
+}
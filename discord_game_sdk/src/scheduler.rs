@@ -0,0 +1,243 @@
+use crate::{
+    discord_future::{self, DiscordFuture, Resolver},
+    Activity, Discord, LobbyID, LobbyMemberTransaction, LobbyTransaction, UserID,
+};
+use std::{
+    collections::VecDeque,
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Queues SDK operations to stay within Discord's rate limits
+///
+/// | Operation                                                             | Limit            |
+/// |-----------------------------------------------------------------------|------------------|
+/// | [`update_activity`](struct.Discord.html#method.update_activity)       | 5 per 20 seconds |
+/// | [`send_lobby_message`](struct.Discord.html#method.send_lobby_message) | 10 per 5 seconds |
+/// | [`update_lobby`](struct.Discord.html#method.update_lobby)             | 10 per 5 seconds |
+/// | [`update_member`](struct.Discord.html#method.update_member)           | 10 per 5 seconds |
+///
+/// Operations are sent immediately while under the limit, and queued otherwise.
+/// Queued operations are sent in order by [`flush`], which should be called as often as
+/// [`run_callbacks`].
+///
+/// Activity updates are coalesced: only the latest queued [`Activity`] is sent, and the futures
+/// of the updates it replaced resolve with its result.
+///
+/// Dropping the scheduler aborts queued operations, their futures resolve to
+/// `Err(TransactionAborted)`.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>) -> Result<()> {
+/// let mut scheduler = Scheduler::new();
+///
+/// for frame in 0.. {
+///     // Sent 5 times every 20 seconds, the latest activity is always eventually sent
+///     let _ = scheduler.update_activity(
+///         &discord,
+///         Activity::empty().with_details(&format!("Frame {}", frame)),
+///     );
+///
+///     scheduler.flush(&discord);
+///     discord.run_callbacks()?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`Activity`]: struct.Activity.html
+/// [`flush`]: #method.flush
+/// [`run_callbacks`]: struct.Discord.html#method.run_callbacks
+pub struct Scheduler {
+    activity_limit: RateLimit,
+    activity: Option<(Activity, Vec<Resolver<()>>)>,
+
+    lobby_message_limit: RateLimit,
+    lobby_messages: VecDeque<(LobbyID, Vec<u8>, Resolver<()>)>,
+
+    lobby_update_limit: RateLimit,
+    lobby_updates: VecDeque<(LobbyID, LobbyTransaction, Resolver<()>)>,
+
+    member_update_limit: RateLimit,
+    member_updates: VecDeque<(LobbyID, UserID, LobbyMemberTransaction, Resolver<()>)>,
+}
+
+// Sliding window of the operations sent during the last `period`
+struct RateLimit {
+    count: usize,
+    period: Duration,
+    sent: VecDeque<Instant>,
+}
+
+impl RateLimit {
+    fn new(count: usize, period: Duration) -> Self {
+        Self {
+            count,
+            period,
+            sent: VecDeque::with_capacity(count),
+        }
+    }
+
+    // Records an operation if the limit allows it
+    fn acquire(&mut self, now: Instant) -> bool {
+        while let Some(&oldest) = self.sent.front() {
+            if now.duration_since(oldest) < self.period {
+                break;
+            }
+
+            self.sent.pop_front();
+        }
+
+        if self.sent.len() < self.count {
+            self.sent.push_back(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Scheduler {
+    /// Creates a scheduler with the documented rate limits and empty queues.
+    pub fn new() -> Self {
+        Self {
+            activity_limit: RateLimit::new(5, Duration::from_secs(20)),
+            activity: None,
+
+            lobby_message_limit: RateLimit::new(10, Duration::from_secs(5)),
+            lobby_messages: VecDeque::new(),
+
+            lobby_update_limit: RateLimit::new(10, Duration::from_secs(5)),
+            lobby_updates: VecDeque::new(),
+
+            member_update_limit: RateLimit::new(10, Duration::from_secs(5)),
+            member_updates: VecDeque::new(),
+        }
+    }
+
+    /// Number of queued operations, a coalesced activity update counts as one.
+    pub fn queue_depth(&self) -> usize {
+        self.activity.iter().count()
+            + self.lobby_messages.len()
+            + self.lobby_updates.len()
+            + self.member_updates.len()
+    }
+
+    /// Calls [`Discord::update_activity`](struct.Discord.html#method.update_activity),
+    /// or queues the activity, replacing any activity already queued.
+    pub fn update_activity<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        activity: &Activity,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+
+        let resolvers = match self.activity.take() {
+            Some((_, mut resolvers)) => {
+                resolvers.push(resolver);
+                resolvers
+            }
+            None => vec![resolver],
+        };
+
+        self.activity = Some((activity.clone(), resolvers));
+        self.flush(discord);
+        future
+    }
+
+    /// Calls [`Discord::send_lobby_message`](struct.Discord.html#method.send_lobby_message),
+    /// or queues the message.
+    pub fn send_lobby_message<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+        buffer: impl Into<Vec<u8>>,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.lobby_messages
+            .push_back((lobby_id, buffer.into(), resolver));
+        self.flush(discord);
+        future
+    }
+
+    /// Calls [`Discord::update_lobby`](struct.Discord.html#method.update_lobby),
+    /// or queues the update.
+    pub fn update_lobby<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+        transaction: &LobbyTransaction,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.lobby_updates
+            .push_back((lobby_id, transaction.clone(), resolver));
+        self.flush(discord);
+        future
+    }
+
+    /// Calls [`Discord::update_member`](struct.Discord.html#method.update_member),
+    /// or queues the update.
+    pub fn update_member<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        transaction: &LobbyMemberTransaction,
+    ) -> DiscordFuture<()> {
+        let (resolver, future) = discord_future::channel();
+        self.member_updates
+            .push_back((lobby_id, user_id, transaction.clone(), resolver));
+        self.flush(discord);
+        future
+    }
+
+    /// Sends as many queued operations as the rate limits allow.
+    pub fn flush<E>(&mut self, discord: &Discord<'_, E>) {
+        let now = Instant::now();
+
+        if self.activity.is_some() && self.activity_limit.acquire(now) {
+            let (activity, resolvers) = self.activity.take().unwrap();
+
+            discord.update_activity(&activity, move |_, result| {
+                for resolver in resolvers {
+                    resolver.resolve(result.clone());
+                }
+            });
+        }
+
+        while !self.lobby_messages.is_empty() && self.lobby_message_limit.acquire(now) {
+            let (lobby_id, buffer, resolver) = self.lobby_messages.pop_front().unwrap();
+
+            discord.send_lobby_message(lobby_id, buffer, |_, result| resolver.resolve(result));
+        }
+
+        while !self.lobby_updates.is_empty() && self.lobby_update_limit.acquire(now) {
+            let (lobby_id, transaction, resolver) = self.lobby_updates.pop_front().unwrap();
+
+            discord.update_lobby(lobby_id, &transaction, |_, result| resolver.resolve(result));
+        }
+
+        while !self.member_updates.is_empty() && self.member_update_limit.acquire(now) {
+            let (lobby_id, user_id, transaction, resolver) =
+                self.member_updates.pop_front().unwrap();
+
+            discord.update_member(lobby_id, user_id, &transaction, |_, result| {
+                resolver.resolve(result)
+            });
+        }
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Scheduler")
+            .field("queue_depth", &self.queue_depth())
+            .finish()
+    }
+}
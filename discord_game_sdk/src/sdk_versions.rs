@@ -0,0 +1,141 @@
+use crate::sys;
+use std::fmt;
+
+/// Versions of the SDK interfaces this crate was built against
+///
+/// These are declared by the SDK header the bindings were generated from, and passed to the
+/// SDK library when creating an instance, see [`sdk_versions`](fn.sdk_versions.html).
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// // "SDK 2 (achievement 1, activity 1, application 1, ...)"
+/// println!("{}", sdk_versions());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SdkVersions {
+    core: sys::DiscordVersion,
+    achievement: sys::DiscordVersion,
+    activity: sys::DiscordVersion,
+    application: sys::DiscordVersion,
+    image: sys::DiscordVersion,
+    lobby: sys::DiscordVersion,
+    network: sys::DiscordVersion,
+    overlay: sys::DiscordVersion,
+    relationship: sys::DiscordVersion,
+    storage: sys::DiscordVersion,
+    store: sys::DiscordVersion,
+    user: sys::DiscordVersion,
+    voice: sys::DiscordVersion,
+}
+
+/// The versions of the SDK interfaces this crate was built against.
+///
+/// Include them when reporting issues, along with the version of the SDK library.
+pub fn sdk_versions() -> SdkVersions {
+    SdkVersions {
+        core: sys::DISCORD_VERSION,
+        achievement: sys::DISCORD_ACHIEVEMENT_MANAGER_VERSION,
+        activity: sys::DISCORD_ACTIVITY_MANAGER_VERSION,
+        application: sys::DISCORD_APPLICATION_MANAGER_VERSION,
+        image: sys::DISCORD_IMAGE_MANAGER_VERSION,
+        lobby: sys::DISCORD_LOBBY_MANAGER_VERSION,
+        network: sys::DISCORD_NETWORK_MANAGER_VERSION,
+        overlay: sys::DISCORD_OVERLAY_MANAGER_VERSION,
+        relationship: sys::DISCORD_RELATIONSHIP_MANAGER_VERSION,
+        storage: sys::DISCORD_STORAGE_MANAGER_VERSION,
+        store: sys::DISCORD_STORE_MANAGER_VERSION,
+        user: sys::DISCORD_USER_MANAGER_VERSION,
+        voice: sys::DISCORD_VOICE_MANAGER_VERSION,
+    }
+}
+
+impl SdkVersions {
+    /// The version of the SDK, passed to `DiscordCreate`
+    pub fn core(&self) -> sys::DiscordVersion {
+        self.core
+    }
+
+    /// The version of the achievement manager
+    pub fn achievement(&self) -> sys::DiscordVersion {
+        self.achievement
+    }
+
+    /// The version of the activity manager
+    pub fn activity(&self) -> sys::DiscordVersion {
+        self.activity
+    }
+
+    /// The version of the application manager
+    pub fn application(&self) -> sys::DiscordVersion {
+        self.application
+    }
+
+    /// The version of the image manager
+    pub fn image(&self) -> sys::DiscordVersion {
+        self.image
+    }
+
+    /// The version of the lobby manager
+    pub fn lobby(&self) -> sys::DiscordVersion {
+        self.lobby
+    }
+
+    /// The version of the network manager
+    pub fn network(&self) -> sys::DiscordVersion {
+        self.network
+    }
+
+    /// The version of the overlay manager
+    pub fn overlay(&self) -> sys::DiscordVersion {
+        self.overlay
+    }
+
+    /// The version of the relationship manager
+    pub fn relationship(&self) -> sys::DiscordVersion {
+        self.relationship
+    }
+
+    /// The version of the storage manager
+    pub fn storage(&self) -> sys::DiscordVersion {
+        self.storage
+    }
+
+    /// The version of the store manager
+    pub fn store(&self) -> sys::DiscordVersion {
+        self.store
+    }
+
+    /// The version of the user manager
+    pub fn user(&self) -> sys::DiscordVersion {
+        self.user
+    }
+
+    /// The version of the voice manager
+    pub fn voice(&self) -> sys::DiscordVersion {
+        self.voice
+    }
+}
+
+impl fmt::Display for SdkVersions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SDK {} (achievement {}, activity {}, application {}, image {}, lobby {}, network {}, \
+             overlay {}, relationship {}, storage {}, store {}, user {}, voice {})",
+            self.core,
+            self.achievement,
+            self.activity,
+            self.application,
+            self.image,
+            self.lobby,
+            self.network,
+            self.overlay,
+            self.relationship,
+            self.storage,
+            self.store,
+            self.user,
+            self.voice,
+        )
+    }
+}
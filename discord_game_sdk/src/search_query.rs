@@ -5,8 +5,8 @@ use crate::{sys, to_result::ToResult, Cast, Comparison, Distance, Result};
 /// > [Struct in official docs](https://discordapp.com/developers/docs/game-sdk/lobbies#data-models-lobbysearchquery-struct)
 #[derive(Clone, Debug, Default)]
 pub struct SearchQuery {
-    pub(crate) filter: Option<(String, String, Comparison, Cast)>,
-    pub(crate) sort: Option<(String, String, Cast)>,
+    pub(crate) filters: Vec<(String, String, Comparison, Cast)>,
+    pub(crate) sorts: Vec<(String, String, Cast)>,
     pub(crate) limit: Option<u32>,
     pub(crate) distance: Option<Distance>,
 }
@@ -21,6 +21,8 @@ impl SearchQuery {
 
     /// Filters lobbies based on metadata comparison.
     ///
+    /// Can be called multiple times, lobbies must then match every filter.
+    ///
     /// ## Performance
     ///
     /// A nul byte will be appended to `key` and `value` if one is not present.
@@ -41,12 +43,14 @@ impl SearchQuery {
             value.push('\0')
         }
 
-        self.filter = Some((key, value, comparison, cast));
+        self.filters.push((key, value, comparison, cast));
         self
     }
 
     /// Sorts the filtered lobbies based on "near-ness" to a given value
     ///
+    /// Can be called multiple times, later sorts break ties of the earlier ones.
+    ///
     /// ## Performance
     ///
     /// A nul byte will be appended to `key` and `value` if one is not present.
@@ -61,7 +65,7 @@ impl SearchQuery {
             value.push('\0')
         }
 
-        self.sort = Some((key, value, cast));
+        self.sorts.push((key, value, cast));
         self
     }
 
@@ -82,7 +86,7 @@ impl SearchQuery {
     }
 
     pub(crate) unsafe fn process(&self, tx: *mut sys::IDiscordLobbySearchQuery) -> Result<()> {
-        if let Some((key, value, comparison, cast)) = self.filter.as_ref() {
+        for (key, value, comparison, cast) in &self.filters {
             (*tx).filter.unwrap()(
                 tx,
                 // XXX: *mut should be *const
@@ -95,7 +99,7 @@ impl SearchQuery {
             .to_result()?;
         }
 
-        if let Some((key, value, cast)) = self.sort.as_ref() {
+        for (key, value, cast) in &self.sorts {
             (*tx).sort.unwrap()(
                 tx,
                 // XXX: *mut should be *const
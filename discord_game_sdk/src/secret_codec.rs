@@ -0,0 +1,333 @@
+use crate::{sys, Action, ActivitySecret, SecretError, UnixTimestamp};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Bumped when the layout changes, older secrets are then `Malformed`
+const FORMAT_VERSION: u8 = 2;
+
+// HMAC-SHA256
+const TAG_LEN: usize = 32;
+
+const HAS_LOBBY: u8 = 1;
+
+const MAX_STR_LEN: usize = 255;
+
+/// Packs [`ActivitySecret`]s into join and spectate secrets, signed with a key of the application
+///
+/// The payload is signed with HMAC-SHA256, along with the action the secret is for,
+/// a spectate secret can't be used to join. It is encoded in URL-safe base64 and must fit
+/// in the SDK's buffer of 127 bytes, which leaves about 45 bytes for the lobby secret,
+/// party ID and claims.
+///
+/// Secrets are signed, not encrypted: everyone who receives one can read its contents,
+/// leave the lobby out of spectate secrets unless spectators may join it.
+/// The expiry limits how long an invite can be replayed.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// const KEY: &[u8] = b"a secret key of the application";
+///
+/// # fn example(discord: Discord<'_, ()>, lobby: &Lobby, now: UnixTimestamp) -> Result<()> {
+/// let codec = SecretCodec::new(KEY);
+///
+/// let secret = codec
+///     .encode(
+///         Action::Join,
+///         ActivitySecret::new(now + 3600).with_lobby(lobby.id(), lobby.secret()),
+///     )
+///     .unwrap();
+///
+/// discord.update_activity(
+///     Activity::empty()
+///         .with_party_id("party-42")
+///         .with_join_secret(&secret),
+///     |_, _| {},
+/// );
+/// # Ok(()) }
+///
+/// struct Handler;
+///
+/// impl EventHandler for Handler {
+///     fn on_activity_join(&mut self, discord: &Discord<'_, Self>, secret: &str) {
+///         match SecretCodec::new(KEY).decode(Action::Join, secret) {
+///             Ok(secret) => {
+///                 if let Some((lobby_id, lobby_secret)) = secret.lobby() {
+///                     discord.connect_lobby(lobby_id, lobby_secret.to_string(), |_, _| {});
+///                 }
+///             }
+///             Err(error) => eprintln!("refusing invite: {}", error),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`ActivitySecret`]: struct.ActivitySecret.html
+#[derive(Clone)]
+pub struct SecretCodec {
+    mac: Hmac<Sha256>,
+}
+
+impl SecretCodec {
+    /// Create a codec signing with `key`, which should be at least 32 random bytes.
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        Self {
+            // HMAC accepts keys of any length
+            mac: Hmac::new_varkey(key.as_ref()).unwrap(),
+        }
+    }
+
+    /// Packs and signs the secret for `action`.
+    ///
+    /// ## Errors
+    ///
+    /// `TooLong` if the encoded secret doesn't fit in the SDK's buffer,
+    /// or if a string is longer than 255 bytes.
+    pub fn encode(&self, action: Action, secret: &ActivitySecret) -> Result<String, SecretError> {
+        let mut payload = vec![FORMAT_VERSION];
+        payload.extend_from_slice(&secret.expires_at.to_be_bytes());
+
+        match &secret.lobby {
+            Some((lobby_id, lobby_secret)) => {
+                payload.push(HAS_LOBBY);
+                payload.extend_from_slice(&lobby_id.to_be_bytes());
+                write_str(&mut payload, lobby_secret)?;
+            }
+            None => payload.push(0),
+        }
+
+        write_str(&mut payload, &secret.party_id)?;
+
+        for (key, value) in &secret.claims {
+            write_str(&mut payload, key)?;
+            write_str(&mut payload, value)?;
+        }
+
+        let tag = self.mac(action, &payload).result().code();
+        payload.extend_from_slice(&tag);
+
+        let encoded = base64::encode_config(&payload, base64::URL_SAFE_NO_PAD);
+        // Leaves room for the NUL terminator
+        let max = sys::DiscordActivitySecrets::default().join.len() - 1;
+
+        if encoded.len() > max {
+            return Err(SecretError::TooLong {
+                len: encoded.len(),
+                max,
+            });
+        }
+
+        Ok(encoded)
+    }
+
+    /// Checks the signature and the expiry of a secret for `action`, and unpacks it.
+    ///
+    /// Call it from [`on_activity_join`] and [`on_activity_spectate`].
+    ///
+    /// ## Errors
+    ///
+    /// `Malformed` or `Tampered` if the secret wasn't encoded by a codec with the same key
+    /// for the same action, `Expired` if it expired.
+    ///
+    /// [`on_activity_join`]: trait.EventHandler.html#method.on_activity_join
+    /// [`on_activity_spectate`]: trait.EventHandler.html#method.on_activity_spectate
+    pub fn decode(&self, action: Action, secret: &str) -> Result<ActivitySecret, SecretError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as UnixTimestamp)
+            .unwrap_or(0);
+
+        self.decode_at(action, secret, now)
+    }
+
+    /// Same as [`decode`](#method.decode), with the current time given in UNIX time.
+    pub fn decode_at(
+        &self,
+        action: Action,
+        secret: &str,
+        now: UnixTimestamp,
+    ) -> Result<ActivitySecret, SecretError> {
+        let bytes = base64::decode_config(secret, base64::URL_SAFE_NO_PAD)
+            .map_err(|_| SecretError::Malformed)?;
+
+        if bytes.len() < TAG_LEN {
+            return Err(SecretError::Malformed);
+        }
+
+        let (payload, tag) = bytes.split_at(bytes.len() - TAG_LEN);
+
+        // Constant time, doesn't reveal how much of the tag matched
+        self.mac(action, payload)
+            .verify(tag)
+            .map_err(|_| SecretError::Tampered)?;
+
+        let decoded = read_payload(payload).ok_or(SecretError::Malformed)?;
+
+        if decoded.expires_at <= now {
+            return Err(SecretError::Expired {
+                expires_at: decoded.expires_at,
+            });
+        }
+
+        Ok(decoded)
+    }
+
+    // HMAC-SHA256 of the action and payload
+    fn mac(&self, action: Action, payload: &[u8]) -> Hmac<Sha256> {
+        let action: sys::EDiscordActivityActionType = action.into();
+
+        let mut mac = self.mac.clone();
+        mac.input(&action.to_be_bytes());
+        mac.input(payload);
+        mac
+    }
+}
+
+impl fmt::Debug for SecretCodec {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SecretCodec").finish()
+    }
+}
+
+fn write_str(payload: &mut Vec<u8>, value: &str) -> Result<(), SecretError> {
+    // Strings are prefixed by their length, as a byte
+    if value.len() > MAX_STR_LEN {
+        return Err(SecretError::TooLong {
+            len: value.len(),
+            max: MAX_STR_LEN,
+        });
+    }
+
+    payload.push(value.len() as u8);
+    payload.extend_from_slice(value.as_bytes());
+    Ok(())
+}
+
+fn read_payload(mut payload: &[u8]) -> Option<ActivitySecret> {
+    if read(&mut payload, 1)? != [FORMAT_VERSION] {
+        return None;
+    }
+
+    let mut secret = ActivitySecret::new(read_i64(&mut payload)?);
+
+    match read(&mut payload, 1)? {
+        [0] => {}
+        [HAS_LOBBY] => secret.lobby = Some((read_i64(&mut payload)?, read_str(&mut payload)?)),
+        _ => return None,
+    }
+
+    secret.party_id = read_str(&mut payload)?;
+
+    while !payload.is_empty() {
+        let key = read_str(&mut payload)?;
+        let value = read_str(&mut payload)?;
+        secret.claims.push((key, value));
+    }
+
+    Some(secret)
+}
+
+fn read<'a>(payload: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if payload.len() < len {
+        return None;
+    }
+
+    let (bytes, rest) = payload.split_at(len);
+    *payload = rest;
+    Some(bytes)
+}
+
+fn read_i64(payload: &mut &[u8]) -> Option<i64> {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(read(payload, 8)?);
+    Some(i64::from_be_bytes(bytes))
+}
+
+fn read_str(payload: &mut &[u8]) -> Option<String> {
+    let len = read(payload, 1)?[0];
+    let bytes = read(payload, len.into())?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let codec = SecretCodec::new("key");
+
+        let mut secret = ActivitySecret::new(1000);
+        secret
+            .with_lobby(42, "d4f1b3e8c2a7")
+            .with_party_id("party")
+            .with_claim("team", "blue");
+
+        let encoded = codec.encode(Action::Join, &secret).unwrap();
+
+        assert_eq!(codec.decode_at(Action::Join, &encoded, 999), Ok(secret));
+        assert_eq!(
+            codec.decode_at(Action::Join, &encoded, 1000),
+            Err(SecretError::Expired { expires_at: 1000 })
+        );
+        assert_eq!(
+            codec.decode_at(Action::Spectate, &encoded, 999),
+            Err(SecretError::Tampered)
+        );
+        assert_eq!(
+            SecretCodec::new("other key").decode_at(Action::Join, &encoded, 999),
+            Err(SecretError::Tampered)
+        );
+
+        let mut tampered = encoded.into_bytes();
+        tampered[12] = if tampered[12] == b'A' { b'B' } else { b'A' };
+        assert_eq!(
+            codec.decode_at(Action::Join, &String::from_utf8(tampered).unwrap(), 999),
+            Err(SecretError::Tampered)
+        );
+
+        assert_eq!(
+            codec.decode_at(Action::Join, "not a secret", 999),
+            Err(SecretError::Malformed)
+        );
+        assert_eq!(
+            codec.encode(
+                Action::Join,
+                ActivitySecret::new(1000).with_party_id("a".repeat(80))
+            ),
+            Err(SecretError::TooLong { len: 164, max: 127 })
+        );
+    }
+
+    #[test]
+    fn rfc_4231() {
+        fn hmac(key: &[u8], data: &[u8]) -> String {
+            let mut mac = SecretCodec::new(key).mac;
+            mac.input(data);
+
+            mac.result()
+                .code()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect()
+        }
+
+        // Test Case 2
+        assert_eq!(
+            hmac(b"Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        // Test Case 6, the key is longer than a block
+        assert_eq!(
+            hmac(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            ),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+}
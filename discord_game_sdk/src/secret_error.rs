@@ -0,0 +1,34 @@
+use crate::UnixTimestamp;
+use std::fmt;
+
+/// Error returned by [`SecretCodec`](struct.SecretCodec.html)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SecretError {
+    /// The encoded secret doesn't fit in the SDK's buffer, or a string is longer than 255 bytes
+    TooLong { len: usize, max: usize },
+
+    /// The string is not a secret encoded by `SecretCodec`
+    Malformed,
+
+    /// The signature doesn't match, the secret was modified, signed with another key,
+    /// or encoded for another action
+    Tampered,
+
+    /// The secret expired
+    Expired { expires_at: UnixTimestamp },
+}
+
+impl fmt::Display for SecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong { len, max } => {
+                write!(f, "secret is {} bytes long, the maximum is {}", len, max)
+            }
+            Self::Malformed => write!(f, "secret is malformed"),
+            Self::Tampered => write!(f, "secret signature doesn't match"),
+            Self::Expired { expires_at } => write!(f, "secret expired at {}", expires_at),
+        }
+    }
+}
+
+impl std::error::Error for SecretError {}
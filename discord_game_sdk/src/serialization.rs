@@ -0,0 +1,754 @@
+// `Serialize` and `Deserialize` for the types wrapping `sys` structs
+//
+// Each type goes through a `*Repr` struct made of its logical fields, strings are read from and
+// written to their fixed-size buffers, failing to deserialize when they don't fit.
+
+use crate::{
+    sys, utils, Activity, ActivityKind, Cast, ClientID, Comparison, Distance, Entitlement,
+    EntitlementKind, FileStat, ImageHandle, ImageKind, InputMode, InputModeKind, Lobby, LobbyID,
+    LobbyKind, LobbyMemberTransaction, LobbyTransaction, OAuth2Token, Presence, Relationship,
+    RelationshipKind, SearchQuery, Sku, SkuKind, Snowflake, Status, UnixTimestamp, User,
+    UserAchievement, UserFlags, UserID,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, collections::BTreeMap, convert::TryFrom};
+
+macro_rules! via_repr {
+    ($($name:ident => $repr:ident,)*) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    $repr::from(self).serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    $repr::deserialize(deserializer)?.into_value()
+                }
+            }
+        )*
+    };
+}
+
+via_repr! {
+    Activity => ActivityRepr,
+    Entitlement => EntitlementRepr,
+    FileStat => FileStatRepr,
+    ImageHandle => ImageHandleRepr,
+    InputMode => InputModeRepr,
+    Lobby => LobbyRepr,
+    LobbyMemberTransaction => LobbyMemberTransactionRepr,
+    LobbyTransaction => LobbyTransactionRepr,
+    OAuth2Token => OAuth2TokenRepr,
+    Presence => PresenceRepr,
+    Relationship => RelationshipRepr,
+    SearchQuery => SearchQueryRepr,
+    Sku => SkuRepr,
+    User => UserRepr,
+    UserAchievement => UserAchievementRepr,
+}
+
+fn write_charbuf<E: de::Error>(charbuf: &mut [u8], value: &str) -> Result<(), E> {
+    if value.len() > charbuf.len() {
+        return Err(E::custom(format_args!(
+            "string of {} bytes does not fit in {} bytes",
+            value.len(),
+            charbuf.len()
+        )));
+    }
+
+    utils::write_charbuf(charbuf, value);
+    Ok(())
+}
+
+fn convert<T: TryFrom<U>, U: Copy + std::fmt::Display, E: de::Error>(value: U) -> Result<T, E> {
+    T::try_from(value).map_err(|_| E::custom(format_args!("{} is out of range", value)))
+}
+
+// Transactions and queries store their strings with a trailing nul
+fn without_nul(value: &str) -> Cow<'_, str> {
+    Cow::Borrowed(value.trim_end_matches('\0'))
+}
+
+fn with_nul(value: Cow<'_, str>) -> String {
+    let mut value = value.into_owned();
+
+    if !value.ends_with('\0') {
+        value.push('\0');
+    }
+
+    value
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Activity")]
+struct ActivityRepr<'a> {
+    kind: ActivityKind,
+    application_id: ClientID,
+    name: Cow<'a, str>,
+    state: Cow<'a, str>,
+    details: Cow<'a, str>,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    large_image_key: Cow<'a, str>,
+    large_image_tooltip: Cow<'a, str>,
+    small_image_key: Cow<'a, str>,
+    small_image_tooltip: Cow<'a, str>,
+    party_id: Cow<'a, str>,
+    party_amount: u32,
+    party_capacity: u32,
+    instance: bool,
+    match_secret: Cow<'a, str>,
+    join_secret: Cow<'a, str>,
+    spectate_secret: Cow<'a, str>,
+}
+
+impl<'a> From<&'a Activity> for ActivityRepr<'a> {
+    fn from(activity: &'a Activity) -> Self {
+        Self {
+            kind: activity.kind(),
+            application_id: activity.application_id(),
+            name: activity.name().into(),
+            state: activity.state().into(),
+            details: activity.details().into(),
+            start_time: activity.start_time(),
+            end_time: activity.end_time(),
+            large_image_key: activity.large_image_key().into(),
+            large_image_tooltip: activity.large_image_tooltip().into(),
+            small_image_key: activity.small_image_key().into(),
+            small_image_tooltip: activity.small_image_tooltip().into(),
+            party_id: activity.party_id().into(),
+            party_amount: activity.party_amount(),
+            party_capacity: activity.party_capacity(),
+            instance: activity.instance(),
+            match_secret: activity.match_secret().into(),
+            join_secret: activity.join_secret().into(),
+            spectate_secret: activity.spectate_secret().into(),
+        }
+    }
+}
+
+impl ActivityRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<Activity, E> {
+        let mut activity = sys::DiscordActivity {
+            type_: self.kind.into(),
+            application_id: self.application_id,
+            instance: self.instance,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut activity.name, &self.name)?;
+        write_charbuf(&mut activity.state, &self.state)?;
+        write_charbuf(&mut activity.details, &self.details)?;
+        activity.timestamps.start = self.start_time;
+        activity.timestamps.end = self.end_time;
+        write_charbuf(&mut activity.assets.large_image, &self.large_image_key)?;
+        write_charbuf(&mut activity.assets.large_text, &self.large_image_tooltip)?;
+        write_charbuf(&mut activity.assets.small_image, &self.small_image_key)?;
+        write_charbuf(&mut activity.assets.small_text, &self.small_image_tooltip)?;
+        write_charbuf(&mut activity.party.id, &self.party_id)?;
+        activity.party.size.current_size = convert(self.party_amount)?;
+        activity.party.size.max_size = convert(self.party_capacity)?;
+        write_charbuf(&mut activity.secrets.match_, &self.match_secret)?;
+        write_charbuf(&mut activity.secrets.join, &self.join_secret)?;
+        write_charbuf(&mut activity.secrets.spectate, &self.spectate_secret)?;
+
+        Ok(Activity(activity))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Entitlement")]
+struct EntitlementRepr {
+    id: Snowflake,
+    kind: EntitlementKind,
+    sku_id: Snowflake,
+}
+
+impl From<&Entitlement> for EntitlementRepr {
+    fn from(entitlement: &Entitlement) -> Self {
+        Self {
+            id: entitlement.id(),
+            kind: entitlement.kind(),
+            sku_id: entitlement.sku_id(),
+        }
+    }
+}
+
+impl EntitlementRepr {
+    fn into_value<E: de::Error>(self) -> Result<Entitlement, E> {
+        Ok(Entitlement(sys::DiscordEntitlement {
+            id: self.id,
+            type_: self.kind.into(),
+            sku_id: self.sku_id,
+        }))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "FileStat")]
+struct FileStatRepr<'a> {
+    filename: Cow<'a, str>,
+    size: u64,
+    last_modified: UnixTimestamp,
+}
+
+impl<'a> From<&'a FileStat> for FileStatRepr<'a> {
+    fn from(file_stat: &'a FileStat) -> Self {
+        Self {
+            filename: file_stat.filename().into(),
+            size: file_stat.size(),
+            last_modified: file_stat.last_modified(),
+        }
+    }
+}
+
+impl FileStatRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<FileStat, E> {
+        let mut file_stat = sys::DiscordFileStat {
+            size: self.size,
+            last_modified: convert(self.last_modified)?,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut file_stat.filename, &self.filename)?;
+
+        Ok(FileStat(file_stat))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ImageHandle")]
+struct ImageHandleRepr {
+    kind: ImageKind,
+    id: Snowflake,
+    size: u32,
+}
+
+impl From<&ImageHandle> for ImageHandleRepr {
+    fn from(handle: &ImageHandle) -> Self {
+        Self {
+            kind: handle.kind(),
+            id: handle.id(),
+            size: handle.size(),
+        }
+    }
+}
+
+impl ImageHandleRepr {
+    fn into_value<E: de::Error>(self) -> Result<ImageHandle, E> {
+        Ok(ImageHandle(sys::DiscordImageHandle {
+            type_: self.kind.into(),
+            id: self.id,
+            size: self.size,
+        }))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "InputMode")]
+struct InputModeRepr<'a> {
+    kind: InputModeKind,
+    shortcut: Cow<'a, str>,
+}
+
+impl<'a> From<&'a InputMode> for InputModeRepr<'a> {
+    fn from(input_mode: &'a InputMode) -> Self {
+        Self {
+            kind: input_mode.kind(),
+            shortcut: input_mode.shortcut().into(),
+        }
+    }
+}
+
+impl InputModeRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<InputMode, E> {
+        let mut input_mode = sys::DiscordInputMode {
+            type_: self.kind.into(),
+            ..Default::default()
+        };
+
+        write_charbuf(&mut input_mode.shortcut, &self.shortcut)?;
+
+        Ok(InputMode(input_mode))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Lobby")]
+struct LobbyRepr<'a> {
+    id: LobbyID,
+    kind: LobbyKind,
+    owner_id: UserID,
+    secret: Cow<'a, str>,
+    capacity: u32,
+    locked: bool,
+}
+
+impl<'a> From<&'a Lobby> for LobbyRepr<'a> {
+    fn from(lobby: &'a Lobby) -> Self {
+        Self {
+            id: lobby.id(),
+            kind: lobby.kind(),
+            owner_id: lobby.owner_id(),
+            secret: lobby.secret().into(),
+            capacity: lobby.capacity(),
+            locked: lobby.locked(),
+        }
+    }
+}
+
+impl LobbyRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<Lobby, E> {
+        let mut lobby = sys::DiscordLobby {
+            id: self.id,
+            type_: self.kind.into(),
+            owner_id: self.owner_id,
+            capacity: self.capacity,
+            locked: self.locked,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut lobby.secret, &self.secret)?;
+
+        Ok(Lobby(lobby))
+    }
+}
+
+// `None` deletes the key
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LobbyMemberTransaction")]
+struct LobbyMemberTransactionRepr<'a> {
+    metadata: BTreeMap<Cow<'a, str>, Option<Cow<'a, str>>>,
+}
+
+impl<'a> From<&'a LobbyMemberTransaction> for LobbyMemberTransactionRepr<'a> {
+    fn from(transaction: &'a LobbyMemberTransaction) -> Self {
+        Self {
+            metadata: transaction
+                .metadata
+                .iter()
+                .map(|(key, value)| (without_nul(key), value.as_ref().map(|v| without_nul(v))))
+                .collect(),
+        }
+    }
+}
+
+impl LobbyMemberTransactionRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<LobbyMemberTransaction, E> {
+        Ok(LobbyMemberTransaction {
+            metadata: self
+                .metadata
+                .into_iter()
+                .map(|(key, value)| (with_nul(key), value.map(with_nul)))
+                .collect(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LobbyTransaction")]
+struct LobbyTransactionRepr<'a> {
+    kind: Option<LobbyKind>,
+    owner: Option<UserID>,
+    capacity: Option<u32>,
+    locked: Option<bool>,
+    metadata: BTreeMap<Cow<'a, str>, Option<Cow<'a, str>>>,
+}
+
+impl<'a> From<&'a LobbyTransaction> for LobbyTransactionRepr<'a> {
+    fn from(transaction: &'a LobbyTransaction) -> Self {
+        Self {
+            kind: transaction.kind,
+            owner: transaction.owner,
+            capacity: transaction.capacity,
+            locked: transaction.locked,
+            metadata: transaction
+                .metadata
+                .iter()
+                .map(|(key, value)| (without_nul(key), value.as_ref().map(|v| without_nul(v))))
+                .collect(),
+        }
+    }
+}
+
+impl LobbyTransactionRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<LobbyTransaction, E> {
+        Ok(LobbyTransaction {
+            kind: self.kind,
+            owner: self.owner,
+            capacity: self.capacity,
+            locked: self.locked,
+            metadata: self
+                .metadata
+                .into_iter()
+                .map(|(key, value)| (with_nul(key), value.map(with_nul)))
+                .collect(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "OAuth2Token")]
+struct OAuth2TokenRepr<'a> {
+    access_token: Cow<'a, str>,
+    scopes: Cow<'a, str>,
+    expires: UnixTimestamp,
+}
+
+impl<'a> From<&'a OAuth2Token> for OAuth2TokenRepr<'a> {
+    fn from(token: &'a OAuth2Token) -> Self {
+        Self {
+            access_token: token.access_token().into(),
+            scopes: token.scopes().into(),
+            expires: token.expires(),
+        }
+    }
+}
+
+impl OAuth2TokenRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<OAuth2Token, E> {
+        let mut token = sys::DiscordOAuth2Token {
+            expires: self.expires,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut token.access_token, &self.access_token)?;
+        write_charbuf(&mut token.scopes, &self.scopes)?;
+
+        Ok(OAuth2Token(token))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Presence")]
+struct PresenceRepr<'a> {
+    status: Status,
+    activity: Cow<'a, Activity>,
+}
+
+impl<'a> From<&'a Presence> for PresenceRepr<'a> {
+    fn from(presence: &'a Presence) -> Self {
+        Self {
+            status: presence.status(),
+            activity: Cow::Borrowed(presence.activity()),
+        }
+    }
+}
+
+impl PresenceRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<Presence, E> {
+        Ok(Presence(sys::DiscordPresence {
+            status: self.status.into(),
+            activity: self.activity.into_owned().0,
+        }))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Relationship")]
+struct RelationshipRepr<'a> {
+    kind: RelationshipKind,
+    user: Cow<'a, User>,
+    presence: Cow<'a, Presence>,
+}
+
+impl<'a> From<&'a Relationship> for RelationshipRepr<'a> {
+    fn from(relationship: &'a Relationship) -> Self {
+        Self {
+            kind: relationship.kind(),
+            user: Cow::Borrowed(relationship.user()),
+            presence: Cow::Borrowed(relationship.presence()),
+        }
+    }
+}
+
+impl RelationshipRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<Relationship, E> {
+        Ok(Relationship(sys::DiscordRelationship {
+            type_: self.kind.into(),
+            user: self.user.into_owned().0,
+            presence: self.presence.into_owned().0,
+        }))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SearchQuery")]
+struct SearchQueryRepr {
+    filters: Vec<(String, String, Comparison, Cast)>,
+    sorts: Vec<(String, String, Cast)>,
+    limit: Option<u32>,
+    distance: Option<Distance>,
+}
+
+impl From<&SearchQuery> for SearchQueryRepr {
+    fn from(query: &SearchQuery) -> Self {
+        Self {
+            filters: query
+                .filters
+                .iter()
+                .map(|(key, value, comparison, cast)| {
+                    (
+                        without_nul(key).into_owned(),
+                        without_nul(value).into_owned(),
+                        *comparison,
+                        *cast,
+                    )
+                })
+                .collect(),
+            sorts: query
+                .sorts
+                .iter()
+                .map(|(key, value, cast)| {
+                    (
+                        without_nul(key).into_owned(),
+                        without_nul(value).into_owned(),
+                        *cast,
+                    )
+                })
+                .collect(),
+            limit: query.limit,
+            distance: query.distance,
+        }
+    }
+}
+
+impl SearchQueryRepr {
+    fn into_value<E: de::Error>(self) -> Result<SearchQuery, E> {
+        Ok(SearchQuery {
+            filters: self
+                .filters
+                .into_iter()
+                .map(|(key, value, comparison, cast)| {
+                    (
+                        with_nul(key.into()),
+                        with_nul(value.into()),
+                        comparison,
+                        cast,
+                    )
+                })
+                .collect(),
+            sorts: self
+                .sorts
+                .into_iter()
+                .map(|(key, value, cast)| (with_nul(key.into()), with_nul(value.into()), cast))
+                .collect(),
+            limit: self.limit,
+            distance: self.distance,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Sku")]
+struct SkuRepr<'a> {
+    id: Snowflake,
+    kind: SkuKind,
+    name: Cow<'a, str>,
+    price_amount: u32,
+    price_currency: Cow<'a, str>,
+}
+
+impl<'a> From<&'a Sku> for SkuRepr<'a> {
+    fn from(sku: &'a Sku) -> Self {
+        Self {
+            id: sku.id(),
+            kind: sku.kind(),
+            name: sku.name().into(),
+            price_amount: sku.price_amount(),
+            price_currency: sku.price_currency().into(),
+        }
+    }
+}
+
+impl SkuRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<Sku, E> {
+        let mut sku = sys::DiscordSku {
+            id: self.id,
+            type_: self.kind.into(),
+            ..Default::default()
+        };
+
+        write_charbuf(&mut sku.name, &self.name)?;
+        sku.price.amount = self.price_amount;
+        write_charbuf(&mut sku.price.currency, &self.price_currency)?;
+
+        Ok(Sku(sku))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "User")]
+struct UserRepr<'a> {
+    id: UserID,
+    username: Cow<'a, str>,
+    discriminator: Cow<'a, str>,
+    avatar: Cow<'a, str>,
+    is_bot: bool,
+}
+
+impl<'a> From<&'a User> for UserRepr<'a> {
+    fn from(user: &'a User) -> Self {
+        Self {
+            id: user.id(),
+            username: user.username().into(),
+            discriminator: user.discriminator().into(),
+            avatar: user.avatar().into(),
+            is_bot: user.is_bot(),
+        }
+    }
+}
+
+impl UserRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<User, E> {
+        let mut user = sys::DiscordUser {
+            id: self.id,
+            bot: self.is_bot,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut user.username, &self.username)?;
+        write_charbuf(&mut user.discriminator, &self.discriminator)?;
+        write_charbuf(&mut user.avatar, &self.avatar)?;
+
+        Ok(User(user))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "UserAchievement")]
+struct UserAchievementRepr<'a> {
+    user_id: UserID,
+    achievement_id: Snowflake,
+    percent_complete: u8,
+    unlocked_at: Cow<'a, str>,
+}
+
+impl<'a> From<&'a UserAchievement> for UserAchievementRepr<'a> {
+    fn from(achievement: &'a UserAchievement) -> Self {
+        Self {
+            user_id: achievement.user_id(),
+            achievement_id: achievement.achievement_id(),
+            percent_complete: achievement.percent_complete(),
+            unlocked_at: achievement.unlocked_at().into(),
+        }
+    }
+}
+
+impl UserAchievementRepr<'_> {
+    fn into_value<E: de::Error>(self) -> Result<UserAchievement, E> {
+        if self.percent_complete > 100 {
+            return Err(E::custom(format_args!(
+                "{} is not a percentage",
+                self.percent_complete
+            )));
+        }
+
+        let mut achievement = sys::DiscordUserAchievement {
+            user_id: self.user_id,
+            achievement_id: self.achievement_id,
+            percent_complete: self.percent_complete,
+            ..Default::default()
+        };
+
+        write_charbuf(&mut achievement.unlocked_at, &self.unlocked_at)?;
+
+        Ok(UserAchievement(achievement))
+    }
+}
+
+impl Serialize for UserFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(UserFlags::from_bits_truncate(Deserialize::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error_context::Call, sys, Activity, Error, ErrorContext, LobbyTransaction,
+        PresenceTemplate, Relationship, Status, User,
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut activity = Activity::empty();
+        activity
+            .with_state("In lobby")
+            .with_party_amount(2)
+            .with_party_capacity(4);
+
+        let json = serde_json::to_string(&activity).unwrap();
+        assert!(json.contains(r#""state":"In lobby""#));
+        assert_eq!(serde_json::from_str::<Activity>(&json).unwrap(), activity);
+
+        let mut relationship = Relationship(sys::DiscordRelationship::default());
+        relationship.0.presence.status = Status::Online.into();
+        relationship.0.presence.activity = activity.0;
+        crate::utils::write_charbuf(&mut relationship.0.user.username, "friend");
+
+        let json = serde_json::to_value(&relationship).unwrap();
+        assert_eq!(json["user"]["username"], "friend");
+        assert_eq!(json["presence"]["status"], "Online");
+        assert_eq!(
+            serde_json::from_value::<Relationship>(json).unwrap(),
+            relationship
+        );
+
+        let transaction = LobbyTransaction::new()
+            .capacity(4)
+            .add_metadata("map".into(), "de_dust2".into())
+            .clone();
+        let json = serde_json::to_value(&transaction).unwrap();
+        assert_eq!(json["metadata"]["map"], "de_dust2");
+        assert_eq!(
+            serde_json::from_value::<LobbyTransaction>(json)
+                .unwrap()
+                .metadata,
+            transaction.metadata
+        );
+
+        let long = "a".repeat(300);
+        assert!(serde_json::from_value::<User>(serde_json::json!({
+            "id": 0,
+            "username": long,
+            "discriminator": "0001",
+            "avatar": "",
+            "is_bot": false,
+        }))
+        .is_err());
+
+        let error = Error::Context(Box::new(ErrorContext {
+            call: Call::new(
+                "lobbies",
+                "delete_lobby",
+                vec![("lobby_id", "42".to_string())],
+            ),
+            error: Error::NotFound,
+        }));
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), error);
+
+        let template = serde_json::from_value::<PresenceTemplate>(serde_json::json!({
+            "state": "{map}",
+        }))
+        .unwrap();
+        assert_eq!(template, PresenceTemplate::new().state("{map}").clone());
+        assert!(
+            serde_json::from_value::<PresenceTemplate>(serde_json::json!({
+                "title": "{map}",
+            }))
+            .is_err()
+        );
+    }
+}
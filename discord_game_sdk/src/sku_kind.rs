@@ -4,6 +4,7 @@ use crate::sys;
 ///
 /// > [Enum in official docs](https://discordapp.com/developers/docs/game-sdk/store#data-models-skutype-enum)
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SkuKind {
     /// SKU is a game
     Application,
@@ -28,3 +29,15 @@ impl From<sys::EDiscordSkuType> for SkuKind {
         }
     }
 }
+
+impl Into<sys::EDiscordSkuType> for SkuKind {
+    fn into(self) -> sys::EDiscordSkuType {
+        match self {
+            Self::Application => sys::DiscordSkuType_Application,
+            Self::Bundle => sys::DiscordSkuType_Bundle,
+            Self::Consumable => sys::DiscordSkuType_Consumable,
+            Self::DLC => sys::DiscordSkuType_DLC,
+            Self::Undefined(n) => n,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Lobby, LobbyID, Relationship, UserID};
+
+/// Change applied to a [`StateMirror`](struct.StateMirror.html)
+///
+/// `old` is `None` when the value was added, `new` is `None` when it was removed.
+/// Relationships and lobbies are boxed to keep the other variants small.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StateChange {
+    /// A relationship was added, updated or removed
+    Relationship {
+        user_id: UserID,
+        old: Option<Box<Relationship>>,
+        new: Option<Box<Relationship>>,
+    },
+
+    /// A lobby was tracked, updated, deleted or forgotten
+    Lobby {
+        lobby_id: LobbyID,
+        old: Option<Box<Lobby>>,
+        new: Option<Box<Lobby>>,
+    },
+
+    /// A lobby metadata value was added, changed or removed
+    LobbyMetadata {
+        lobby_id: LobbyID,
+        key: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+
+    /// A member connected to or disconnected from a lobby
+    Member {
+        lobby_id: LobbyID,
+        user_id: UserID,
+        connected: bool,
+    },
+
+    /// A member metadata value was added, changed or removed
+    MemberMetadata {
+        lobby_id: LobbyID,
+        user_id: UserID,
+        key: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
@@ -0,0 +1,403 @@
+use crate::{Discord, Event, Lobby, LobbyID, Relationship, Result, StateChange, UserID};
+use std::collections::BTreeMap;
+
+/// Owned copies of relationships, lobbies and lobby members, kept up to date by [`Event`]s
+///
+/// The SDK only provides index-based getters and notifications carrying IDs, this keeps
+/// queryable copies of their data and reports what changed as a list of [`StateChange`]s.
+///
+/// Relationships are mirrored once [`Event::RelationshipsRefresh`] is received, or after calling
+/// [`sync`]. They are read through the current [`filter_relationships`] filter, which the mirror
+/// never changes: relationships it excludes are not mirrored on refreshes.
+///
+/// The SDK fires no event when the current user connects to a lobby, lobbies are
+/// mirrored after calling [`track_lobby`], until they are deleted or [`forget_lobby`] is called.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # fn example(mut discord: Discord<'_, ()>, lobby_id: LobbyID) -> Result<()> {
+/// let mut mirror = StateMirror::new();
+/// discord.set_event_buffering(true);
+///
+/// mirror.track_lobby(&discord, lobby_id)?;
+///
+/// loop {
+///     let events = discord.poll_events()?.collect::<Vec<_>>();
+///
+///     for event in &events {
+///         for change in mirror.update(&discord, event) {
+///             if let StateChange::MemberMetadata { user_id, key, old, new, .. } = change {
+///                 println!("member {} changed {} from {:?} to {:?}", user_id, key, old, new);
+///             }
+///         }
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`Event`]: enum.Event.html
+/// [`Event::RelationshipsRefresh`]: enum.Event.html#variant.RelationshipsRefresh
+/// [`StateChange`]: enum.StateChange.html
+/// [`filter_relationships`]: struct.Discord.html#method.filter_relationships
+/// [`forget_lobby`]: #method.forget_lobby
+/// [`sync`]: #method.sync
+/// [`track_lobby`]: #method.track_lobby
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StateMirror {
+    relationships: BTreeMap<UserID, Relationship>,
+    lobbies: BTreeMap<LobbyID, MirroredLobby>,
+}
+
+/// Owned copy of a lobby, its metadata and its members' metadata, see
+/// [`StateMirror`](struct.StateMirror.html)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MirroredLobby {
+    lobby: Lobby,
+    metadata: BTreeMap<String, String>,
+    members: BTreeMap<UserID, BTreeMap<String, String>>,
+}
+
+impl MirroredLobby {
+    /// The lobby
+    pub fn lobby(&self) -> &Lobby {
+        &self.lobby
+    }
+
+    /// The lobby metadata
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// The IDs of the connected members
+    pub fn member_ids(&self) -> impl '_ + Iterator<Item = UserID> {
+        self.members.keys().cloned()
+    }
+
+    /// The metadata of a connected member
+    pub fn member_metadata(&self, user_id: UserID) -> Option<&BTreeMap<String, String>> {
+        self.members.get(&user_id)
+    }
+}
+
+impl StateMirror {
+    /// Creates an empty mirror
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The mirrored relationships
+    pub fn relationships(&self) -> impl '_ + Iterator<Item = &Relationship> {
+        self.relationships.values()
+    }
+
+    /// The mirrored relationship with a given user
+    pub fn relationship(&self, user_id: UserID) -> Option<&Relationship> {
+        self.relationships.get(&user_id)
+    }
+
+    /// The mirrored lobbies
+    pub fn lobbies(&self) -> impl '_ + Iterator<Item = &MirroredLobby> {
+        self.lobbies.values()
+    }
+
+    /// A mirrored lobby
+    pub fn lobby(&self, lobby_id: LobbyID) -> Option<&MirroredLobby> {
+        self.lobbies.get(&lobby_id)
+    }
+
+    /// Applies an [`Event`], reading the new state from `discord`.
+    ///
+    /// Events that are unrelated, or that concern lobbies that aren't tracked, are ignored.
+    /// Relationships are read again on [`Event::RelationshipsRefresh`], through the current
+    /// relationship filter, which is left unchanged.
+    /// Errors while reading the new state are logged, changes read before the error are kept.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Event::RelationshipsRefresh`]: enum.Event.html#variant.RelationshipsRefresh
+    pub fn update<E>(&mut self, discord: &Discord<'_, E>, event: &Event) -> Vec<StateChange> {
+        let mut changes = Vec::new();
+
+        let result = match *event {
+            Event::RelationshipsRefresh => self.sync_relationships(discord, &mut changes),
+
+            Event::RelationshipUpdate { ref relationship } => {
+                self.set_relationship(relationship.clone(), &mut changes);
+                Ok(())
+            }
+
+            Event::LobbyUpdate { lobby_id } if self.lobbies.contains_key(&lobby_id) => {
+                self.sync_lobby(discord, lobby_id, &mut changes)
+            }
+
+            Event::LobbyDelete { lobby_id, .. } => {
+                self.remove_lobby(lobby_id, &mut changes);
+                Ok(())
+            }
+
+            Event::MemberConnect {
+                lobby_id,
+                member_id,
+            }
+            | Event::MemberUpdate {
+                lobby_id,
+                member_id,
+            } if self.lobbies.contains_key(&lobby_id) => {
+                self.sync_member(discord, lobby_id, member_id, &mut changes)
+            }
+
+            Event::MemberDisconnect {
+                lobby_id,
+                member_id,
+            } => {
+                self.remove_member(lobby_id, member_id, &mut changes);
+                Ok(())
+            }
+
+            _ => Ok(()),
+        };
+
+        if let Err(error) = result {
+            log::warn!("failed to mirror {:?}: {}", event, error);
+        }
+
+        changes
+    }
+
+    /// Starts mirroring a lobby the current user is connected to, and reads its state.
+    pub fn track_lobby<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+    ) -> Result<Vec<StateChange>> {
+        let mut changes = Vec::new();
+        self.sync_lobby(discord, lobby_id, &mut changes)?;
+        Ok(changes)
+    }
+
+    /// Stops mirroring a lobby, after disconnecting from it.
+    pub fn forget_lobby(&mut self, lobby_id: LobbyID) -> Vec<StateChange> {
+        let mut changes = Vec::new();
+        self.remove_lobby(lobby_id, &mut changes);
+        changes
+    }
+
+    /// Reads the state of the relationships matching the current relationship filter, and of
+    /// tracked lobbies, again.
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::NotFetched`](enum.Error.html#variant.NotFetched) if relationships were not
+    /// fetched by the SDK yet.
+    pub fn sync<E>(&mut self, discord: &Discord<'_, E>) -> Result<Vec<StateChange>> {
+        let mut changes = Vec::new();
+
+        self.sync_relationships(discord, &mut changes)?;
+
+        let lobby_ids = self.lobbies.keys().cloned().collect::<Vec<_>>();
+
+        for lobby_id in lobby_ids {
+            self.sync_lobby(discord, lobby_id, &mut changes)?;
+        }
+
+        Ok(changes)
+    }
+
+    fn sync_relationships<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        changes: &mut Vec<StateChange>,
+    ) -> Result<()> {
+        let relationships = discord.iter_relationships()?.collect::<Result<Vec<_>>>()?;
+
+        let removed = self
+            .relationships
+            .keys()
+            .filter(|user_id| relationships.iter().all(|r| r.user().id() != **user_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for user_id in removed {
+            let old = self.relationships.remove(&user_id);
+
+            changes.push(StateChange::Relationship {
+                user_id,
+                old: old.map(Box::new),
+                new: None,
+            });
+        }
+
+        for relationship in relationships {
+            self.set_relationship(relationship, changes);
+        }
+
+        Ok(())
+    }
+
+    fn set_relationship(&mut self, relationship: Relationship, changes: &mut Vec<StateChange>) {
+        let user_id = relationship.user().id();
+        let old = self.relationships.insert(user_id, relationship.clone());
+
+        if old.as_ref() != Some(&relationship) {
+            changes.push(StateChange::Relationship {
+                user_id,
+                old: old.map(Box::new),
+                new: Some(Box::new(relationship)),
+            });
+        }
+    }
+
+    fn sync_lobby<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+        changes: &mut Vec<StateChange>,
+    ) -> Result<()> {
+        let lobby = discord.lobby(lobby_id)?;
+        let metadata = discord
+            .iter_lobby_metadata(lobby_id)?
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        let member_ids = discord
+            .iter_lobby_member_ids(lobby_id)?
+            .collect::<Result<Vec<_>>>()?;
+
+        let old = self
+            .lobbies
+            .get(&lobby_id)
+            .map(|mirrored| mirrored.lobby.clone());
+
+        if old.as_ref() != Some(&lobby) {
+            changes.push(StateChange::Lobby {
+                lobby_id,
+                old: old.map(Box::new),
+                new: Some(Box::new(lobby.clone())),
+            });
+        }
+
+        let mirrored = self
+            .lobbies
+            .entry(lobby_id)
+            .or_insert_with(|| MirroredLobby {
+                lobby: lobby.clone(),
+                metadata: BTreeMap::new(),
+                members: BTreeMap::new(),
+            });
+
+        mirrored.lobby = lobby;
+
+        diff_metadata(&mut mirrored.metadata, metadata, |key, old, new| {
+            changes.push(StateChange::LobbyMetadata {
+                lobby_id,
+                key,
+                old,
+                new,
+            })
+        });
+
+        let disconnected = mirrored
+            .members
+            .keys()
+            .filter(|user_id| !member_ids.contains(user_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for user_id in disconnected {
+            self.remove_member(lobby_id, user_id, changes);
+        }
+
+        for user_id in member_ids {
+            self.sync_member(discord, lobby_id, user_id, changes)?;
+        }
+
+        Ok(())
+    }
+
+    fn remove_lobby(&mut self, lobby_id: LobbyID, changes: &mut Vec<StateChange>) {
+        if let Some(mirrored) = self.lobbies.remove(&lobby_id) {
+            changes.push(StateChange::Lobby {
+                lobby_id,
+                old: Some(Box::new(mirrored.lobby)),
+                new: None,
+            });
+        }
+    }
+
+    fn sync_member<E>(
+        &mut self,
+        discord: &Discord<'_, E>,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        changes: &mut Vec<StateChange>,
+    ) -> Result<()> {
+        let metadata = discord
+            .iter_lobby_member_metadata(lobby_id, user_id)?
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        let members = match self.lobbies.get_mut(&lobby_id) {
+            Some(mirrored) => &mut mirrored.members,
+            None => return Ok(()),
+        };
+
+        let mirrored = members.entry(user_id).or_insert_with(|| {
+            changes.push(StateChange::Member {
+                lobby_id,
+                user_id,
+                connected: true,
+            });
+
+            BTreeMap::new()
+        });
+
+        diff_metadata(mirrored, metadata, |key, old, new| {
+            changes.push(StateChange::MemberMetadata {
+                lobby_id,
+                user_id,
+                key,
+                old,
+                new,
+            })
+        });
+
+        Ok(())
+    }
+
+    fn remove_member(
+        &mut self,
+        lobby_id: LobbyID,
+        user_id: UserID,
+        changes: &mut Vec<StateChange>,
+    ) {
+        let removed = self
+            .lobbies
+            .get_mut(&lobby_id)
+            .and_then(|mirrored| mirrored.members.remove(&user_id));
+
+        if removed.is_some() {
+            changes.push(StateChange::Member {
+                lobby_id,
+                user_id,
+                connected: false,
+            });
+        }
+    }
+}
+
+// Replaces `current` with `new`, calling `change` for every key that differs
+fn diff_metadata(
+    current: &mut BTreeMap<String, String>,
+    new: BTreeMap<String, String>,
+    mut change: impl FnMut(String, Option<String>, Option<String>),
+) {
+    let old = std::mem::replace(current, new);
+
+    for (key, value) in current.iter() {
+        if old.get(key) != Some(value) {
+            change(key.clone(), old.get(key).cloned(), Some(value.clone()));
+        }
+    }
+
+    for (key, value) in old {
+        if !current.contains_key(&key) {
+            change(key, Some(value), None);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    discord_future::{self, DiscordFuture},
+    Activity, ClientID, ConnectionState, CreateFlags, Discord, Error, EventHandler, Result,
+};
+use std::{
+    cmp, fmt,
+    time::{Duration, Instant},
+};
+
+/// Keeps a [`Discord`] instance connected, recreating it when the client goes away
+///
+/// Instances are created with [`CreateFlags::NoRequireDiscord`]. When [`run_callbacks`] fails with
+/// `NotRunning` or `ServiceUnavailable`, the instance is dropped and creation is retried with
+/// exponential backoff.
+///
+/// The [`EventHandler`], the last [`Activity`] and the launch command set through the supervisor
+/// are carried over to new instances.
+///
+/// ```rust
+/// # use discord_game_sdk::*;
+/// # const DISCORD_CLIENT_ID: ClientID = 0;
+/// # fn example() -> Result<()> {
+/// let mut supervisor = Supervisor::<()>::new(DISCORD_CLIENT_ID);
+///
+/// // Applied now if connected, and again after every reconnect
+/// let _ = supervisor.register_launch_command("my-game --discord");
+/// let _ = supervisor.update_activity(Activity::empty().with_state("In menu"));
+///
+/// loop {
+///     match supervisor.run_callbacks()? {
+///         Some(ConnectionState::Connected) => println!("connected to Discord"),
+///         Some(ConnectionState::Disconnected) => println!("lost connection to Discord"),
+///         _ => {}
+///     }
+///
+///     if let Some(discord) = supervisor.discord() {
+///         // ...
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [`Activity`]: struct.Activity.html
+/// [`CreateFlags::NoRequireDiscord`]: enum.CreateFlags.html#variant.NoRequireDiscord
+/// [`Discord`]: struct.Discord.html
+/// [`EventHandler`]: trait.EventHandler.html
+/// [`run_callbacks`]: #method.run_callbacks
+pub struct Supervisor<E> {
+    create: Box<dyn FnMut() -> Result<Discord<'static, E>>>,
+    discord: Option<Discord<'static, E>>,
+    // Kept here while there is no instance
+    event_handler: Option<E>,
+    state: ConnectionState,
+
+    min_backoff: Duration,
+    max_backoff: Duration,
+    backoff: Duration,
+    next_attempt: Instant,
+
+    activity: Option<Activity>,
+    launch_command: Option<String>,
+}
+
+impl<E: 'static + EventHandler> Supervisor<E> {
+    /// Calls [`with_create`](#method.with_create) with
+    /// [`Discord::with_create_flags`](struct.Discord.html#method.with_create_flags)
+    /// and [`CreateFlags::NoRequireDiscord`](enum.CreateFlags.html#variant.NoRequireDiscord).
+    pub fn new(client_id: ClientID) -> Self {
+        Self::with_create(move || {
+            Discord::with_create_flags(client_id, CreateFlags::NoRequireDiscord)
+        })
+    }
+
+    /// Creates a supervisor that creates instances with `create`.
+    ///
+    /// A first instance is created immediately.
+    pub fn with_create(create: impl 'static + FnMut() -> Result<Discord<'static, E>>) -> Self {
+        let min_backoff = Duration::from_secs(1);
+
+        let mut supervisor = Self {
+            create: Box::new(create),
+            discord: None,
+            event_handler: None,
+            state: ConnectionState::Disconnected,
+
+            min_backoff,
+            max_backoff: Duration::from_secs(60),
+            backoff: min_backoff,
+            next_attempt: Instant::now(),
+
+            activity: None,
+            launch_command: None,
+        };
+
+        supervisor.connect();
+        supervisor
+    }
+}
+
+impl<E> Supervisor<E> {
+    /// The current state of the connection.
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// The instance, if connected.
+    pub fn discord(&self) -> Option<&Discord<'static, E>> {
+        self.discord.as_ref()
+    }
+
+    /// The instance, if connected.
+    pub fn discord_mut(&mut self) -> Option<&mut Discord<'static, E>> {
+        self.discord.as_mut()
+    }
+
+    /// The [`EventHandler`](trait.EventHandler.html), carried over to new instances.
+    pub fn event_handler(&self) -> &Option<E> {
+        match &self.discord {
+            Some(discord) => discord.event_handler(),
+            None => &self.event_handler,
+        }
+    }
+
+    /// The [`EventHandler`](trait.EventHandler.html), carried over to new instances.
+    pub fn event_handler_mut(&mut self) -> &mut Option<E> {
+        match &mut self.discord {
+            Some(discord) => discord.event_handler_mut(),
+            None => &mut self.event_handler,
+        }
+    }
+
+    /// Sets the delays between attempts at creating an instance.
+    ///
+    /// The delay starts at `min` and doubles after every failed attempt, up to `max`.
+    /// Defaults to 1 second and 60 seconds.
+    pub fn set_backoff(&mut self, min: Duration, max: Duration) {
+        self.min_backoff = min;
+        self.max_backoff = cmp::max(min, max);
+        self.backoff = min;
+    }
+
+    /// Runs all pending SDK callbacks, or attempts to create an instance if it is time to.
+    ///
+    /// Returns the new state if it changed.
+    ///
+    /// ## Errors
+    ///
+    /// Errors returned by [`Discord::run_callbacks`](struct.Discord.html#method.run_callbacks),
+    /// other than `NotRunning` and `ServiceUnavailable`.
+    pub fn run_callbacks(&mut self) -> Result<Option<ConnectionState>> {
+        let previous = self.state;
+
+        match self.discord.as_mut().map(Discord::run_callbacks) {
+            Some(Ok(())) => {}
+            Some(Err(Error::NotRunning)) | Some(Err(Error::ServiceUnavailable)) => {
+                self.disconnect()
+            }
+            Some(Err(error)) => return Err(error),
+            None if Instant::now() >= self.next_attempt => self.connect(),
+            None => {}
+        }
+
+        Ok(if self.state != previous {
+            Some(self.state)
+        } else {
+            None
+        })
+    }
+
+    /// Calls [`Discord::update_activity`](struct.Discord.html#method.update_activity),
+    /// the activity is also applied to new instances.
+    ///
+    /// The future resolves to `Err(NotRunning)` if not connected.
+    pub fn update_activity(&mut self, activity: &Activity) -> DiscordFuture<()> {
+        self.activity = Some(activity.clone());
+
+        match &self.discord {
+            Some(discord) => discord.futures().update_activity(activity),
+            None => discord_future::ready(Err(Error::NotRunning)),
+        }
+    }
+
+    /// Calls [`Discord::clear_activity`](struct.Discord.html#method.clear_activity),
+    /// new instances will not have an activity.
+    ///
+    /// The future resolves to `Err(NotRunning)` if not connected.
+    pub fn clear_activity(&mut self) -> DiscordFuture<()> {
+        self.activity = None;
+
+        match &self.discord {
+            Some(discord) => discord.futures().clear_activity(),
+            None => discord_future::ready(Err(Error::NotRunning)),
+        }
+    }
+
+    /// Calls [`Discord::register_launch_command`](struct.Discord.html#method.register_launch_command),
+    /// the command is also registered for new instances.
+    ///
+    /// ## Errors
+    ///
+    /// `NotRunning` if not connected.
+    pub fn register_launch_command(&mut self, command: impl Into<String>) -> Result<()> {
+        let command = command.into();
+        self.launch_command = Some(command.clone());
+
+        match &self.discord {
+            Some(discord) => discord.register_launch_command(command),
+            None => Err(Error::NotRunning),
+        }
+    }
+
+    fn connect(&mut self) {
+        match (self.create)() {
+            Ok(mut discord) => {
+                log::debug!("supervisor connected");
+
+                *discord.event_handler_mut() = self.event_handler.take();
+                self.restore(&discord);
+
+                self.discord = Some(discord);
+                self.backoff = self.min_backoff;
+                self.state = ConnectionState::Connected;
+            }
+
+            Err(error) => {
+                log::debug!(
+                    "supervisor could not connect: {}, retrying in {:?}",
+                    error,
+                    self.backoff
+                );
+
+                self.next_attempt = Instant::now() + self.backoff;
+                self.backoff = cmp::min(self.backoff * 2, self.max_backoff);
+                self.state = ConnectionState::Reconnecting;
+            }
+        }
+    }
+
+    fn disconnect(&mut self) {
+        log::debug!("supervisor lost connection");
+
+        if let Some(mut discord) = self.discord.take() {
+            self.event_handler = discord.event_handler_mut().take();
+        }
+
+        self.next_attempt = Instant::now() + self.backoff;
+        self.state = ConnectionState::Disconnected;
+    }
+
+    fn restore(&self, discord: &Discord<'static, E>) {
+        if let Some(command) = &self.launch_command {
+            if let Err(error) = discord.register_launch_command(command.as_str()) {
+                log::warn!("supervisor could not restore launch command: {}", error);
+            }
+        }
+
+        if let Some(activity) = &self.activity {
+            discord.update_activity(activity, |_, result| {
+                if let Err(error) = result {
+                    log::warn!("supervisor could not restore activity: {}", error);
+                }
+            });
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Supervisor<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Supervisor")
+            .field("discord", &self.discord)
+            .field("state", &self.state)
+            .field("activity", &self.activity)
+            .field("launch_command", &self.launch_command)
+            .finish()
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ActivityError;
+use std::fmt;
+
+/// Error returned by [`PresenceTemplate::render`](struct.PresenceTemplate.html#method.render)
+///
+/// `field` is the name of the [`PresenceTemplate`](struct.PresenceTemplate.html) method that
+/// set the template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TemplateError {
+    /// A brace at this byte offset doesn't open or close a placeholder,
+    /// braces are written `{{` and `}}`
+    InvalidSyntax { field: &'static str, offset: usize },
+
+    /// The placeholder names a variable that wasn't given
+    MissingVariable { field: &'static str, name: String },
+
+    /// The rendered activity failed validation, strings may be too long
+    Activity(ActivityError),
+}
+
+impl TemplateError {
+    /// The validation error of the rendered activity, if it failed validation
+    pub fn activity_error(&self) -> Option<&ActivityError> {
+        match self {
+            Self::Activity(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax { field, offset } => {
+                write!(f, "{} has an unmatched brace at byte {}", field, offset)
+            }
+            Self::MissingVariable { field, name } => {
+                write!(f, "{} uses the undefined variable {}", field, name)
+            }
+            Self::Activity(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Activity(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{sys, Error, Result};
+use crate::{sdk_versions, sys, Error, Result};
 
 pub(crate) trait ToResult: Sized {
     fn to_result(self) -> Result<()>;
@@ -11,7 +11,10 @@ impl ToResult for sys::EDiscordResult {
         Err(match self {
             sys::DiscordResult_Ok => return Ok(()),
             sys::DiscordResult_ServiceUnavailable => ServiceUnavailable,
-            sys::DiscordResult_InvalidVersion => InvalidVersion,
+            sys::DiscordResult_InvalidVersion => InvalidVersion {
+                expected: sdk_versions(),
+                actual: None,
+            },
             sys::DiscordResult_LockFailed => LockFailed,
             sys::DiscordResult_InternalError => Internal,
             sys::DiscordResult_InvalidPayload => InvalidPayload,
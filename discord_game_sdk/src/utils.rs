@@ -1,4 +1,6 @@
+use crate::discord::DiscordInner;
 use scopeguard::{OnSuccess, ScopeGuard};
+use std::{any::Any, panic::AssertUnwindSafe};
 
 type PanicHook = Box<dyn Fn(&std::panic::PanicInfo<'_>) + Sync + Send + 'static>;
 
@@ -26,6 +28,47 @@ pub(crate) fn prevent_unwind() -> ScopeGuard<PanicHook, fn(PanicHook), OnSuccess
     })
 }
 
+// Runs user code called from the SDK
+// Panics abort the process, unless `catch_panics` is enabled, then they're stored for
+// `run_callbacks` to return
+pub(crate) fn guard_callback<E>(inner: *mut DiscordInner<'_, E>, callback: impl FnOnce()) {
+    debug_assert!(!inner.is_null());
+
+    // SAFETY: `catch_panics` is only mutated through `&mut Discord`, which can't exist during
+    // callbacks
+    if !unsafe { (*inner).catch_panics } {
+        let _guard = prevent_unwind();
+        return callback();
+    }
+
+    if let Err(payload) = std::panic::catch_unwind(AssertUnwindSafe(callback)) {
+        let message = panic_message(&*payload);
+
+        log::error!("caught panic in callback: {}", message);
+
+        // SAFETY: Mutating through an immutable reference
+        // - `panic` is an `UnsafeCell`, inner mutation is legal
+        // - It is only otherwise accessed by `run_callbacks`, which can't run during callbacks
+        unsafe {
+            let panic = &mut *(*inner).panic.get();
+
+            if panic.is_none() {
+                *panic = Some(message);
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<Any>".to_string()
+    }
+}
+
 pub(crate) fn charbuf_to_str(charbuf: &[u8]) -> &str {
     let bytes = &charbuf[..charbuf_len(charbuf)];
 
@@ -0,0 +1,87 @@
+// These tests go through the SDK's C interface, they must be linked against
+// `discord_game_sdk_stub` instead of the SDK, see its documentation
+#![cfg(feature = "link")]
+
+use discord_game_sdk::{CreateFlags, Discord, Error, EventHandler, LobbyID, LobbyTransaction};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Default)]
+struct Events(Vec<LobbyID>);
+
+impl EventHandler for Events {
+    fn on_lobby_update(&mut self, _: &Discord<'_, Self>, lobby_id: LobbyID) {
+        self.0.push(lobby_id);
+    }
+}
+
+#[test]
+#[ignore]
+fn create() {
+    let discord = Discord::<()>::with_create_flags(0, CreateFlags::NoRequireDiscord).unwrap();
+
+    assert_eq!(discord.current_user().unwrap().username(), "mock");
+    assert_eq!(discord.current_locale(), "en-US");
+
+    // Only one instance per backend
+    assert_eq!(
+        Discord::<()>::new(0)
+            .err()
+            .map(|error| error.kind().clone()),
+        Some(Error::Internal)
+    );
+
+    drop(discord);
+
+    assert!(Discord::<()>::new(0).is_ok());
+}
+
+#[test]
+#[ignore]
+fn lobbies() {
+    let mut discord = Discord::new(0).unwrap();
+    *discord.event_handler_mut() = Some(Events::default());
+
+    let created = Rc::new(RefCell::new(None));
+    let created_ = created.clone();
+
+    discord.create_lobby(
+        LobbyTransaction::new()
+            .capacity(4)
+            .add_metadata("map".into(), "de_dust2".into()),
+        move |_, lobby| *created_.borrow_mut() = Some(lobby.unwrap().id()),
+    );
+
+    discord.run_callbacks().unwrap();
+
+    let lobby_id = created.borrow().unwrap();
+
+    assert_eq!(discord.lobby(lobby_id).unwrap().capacity(), 4);
+    assert_eq!(discord.lobby_metadata(lobby_id, "map").unwrap(), "de_dust2");
+
+    discord.update_lobby(
+        lobby_id,
+        LobbyTransaction::new().add_metadata("map".into(), "de_nuke".into()),
+        |_, res| res.unwrap(),
+    );
+
+    discord.run_callbacks().unwrap();
+
+    assert_eq!(discord.lobby_metadata(lobby_id, "map").unwrap(), "de_nuke");
+    assert_eq!(discord.event_handler().as_ref().unwrap().0, vec![lobby_id]);
+}
+
+#[test]
+#[ignore]
+fn storage() {
+    let discord = Discord::<()>::new(0).unwrap();
+    let mut buffer = [0; 16];
+
+    discord.write_file("save", b"hello").unwrap();
+
+    assert_eq!(discord.read_file("save", &mut buffer[..]).unwrap(), 5);
+    assert_eq!(&buffer[..5], b"hello");
+    assert_eq!(
+        discord.read_file("missing", &mut buffer[..]),
+        Err(Error::NotFound)
+    );
+}
@@ -0,0 +1,53 @@
+//! This crate builds a stand-in for the [Discord Game SDK] library.
+//!
+//! *This crate is not official, it is not supported by the Discord Game SDK Developers.*
+//!
+//! It exports `DiscordCreate` with the C ABI of `discord_game_sdk_sys`, backed by
+//! `discord_game_sdk::MockBackend`, an in-process fake of every manager.
+//! Programs linked against it with the `link` feature go through the same FFI calls
+//! as with the SDK, without a Discord client.
+//!
+//!
+//! # Usage
+//!
+//! Build it without the `link` feature, then install it where the `link` feature expects the SDK:
+//!
+//! ```sh
+//! export DISCORD_GAME_SDK_BINDINGS=2.5.6
+//! cargo build -p discord_game_sdk_stub
+//!
+//! mkdir -p stub/lib/x86_64
+//! cp target/debug/libdiscord_game_sdk_stub.so stub/lib/x86_64/libdiscord_game_sdk.so
+//!
+//! export DISCORD_GAME_SDK_PATH=$PWD/stub
+//! export LD_LIBRARY_PATH=${LD_LIBRARY_PATH:+${LD_LIBRARY_PATH}:}$DISCORD_GAME_SDK_PATH/lib/x86_64
+//! cargo test -p discord_game_sdk --test stub -- --ignored
+//! ```
+//!
+//! Every thread gets its own backend, with the default data of `MockBackend::new`.
+//! Only one instance may be created at a time per thread.
+//!
+//! [Discord Game SDK]: https://discordapp.com/developers/docs/game-sdk/sdk-starter-guide
+
+use discord_game_sdk::MockBackend;
+use discord_game_sdk_sys as sys;
+
+thread_local! {
+    // `MockBackend` can't be shared between threads
+    static BACKEND: MockBackend = MockBackend::new();
+}
+
+/// Creates an instance backed by the calling thread's backend, same as the SDK's `DiscordCreate`
+///
+/// ## Safety
+///
+/// See `MockBackend::discord_create`.
+#[no_mangle]
+#[allow(non_snake_case)]
+pub unsafe extern "C" fn DiscordCreate(
+    version: sys::DiscordVersion,
+    params: *mut sys::DiscordCreateParams,
+    result: *mut *mut sys::IDiscordCore,
+) -> sys::EDiscordResult {
+    BACKEND.with(|backend| backend.discord_create(version, params, result))
+}
@@ -1,19 +1,71 @@
-use std::{env, error::Error, path::PathBuf};
+use std::{
+    env,
+    error::Error,
+    path::{Path, PathBuf},
+};
 
 // TODO make sure references are used when they should be
 #[cfg(feature = "download")]
 fn dl_sdk(sdkfolder: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
-    use std::fs::{self, File};
-    use std::io::{self, Cursor};
+    use std::io::Cursor;
 
     use reqwest::blocking::get;
-    use zip::read::ZipArchive;
 
     // Download and extract SDK
     let req = get("https://dl-game-sdk.discordapp.net/latest/discord_game_sdk.zip")?
         .error_for_status()?;
-    let mut req = Cursor::new(req.bytes()?);
-    let mut archive = ZipArchive::new(&mut req)?;
+
+    extract_sdk(Cursor::new(req.bytes()?), sdkfolder)
+}
+
+#[cfg(not(feature = "download"))]
+fn dl_sdk(_: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
+    Err(std::io::Error::new(std::io::ErrorKind::Other, "Download disabled").into())
+}
+
+#[cfg(feature = "archive")]
+fn unzip_sdk(
+    zip_path: &Path,
+    sha256: Option<String>,
+    sdkfolder: PathBuf,
+) -> Result<PathBuf, Box<dyn Error>> {
+    use sha2::{Digest, Sha256};
+
+    let bytes = std::fs::read(zip_path)?;
+
+    if let Some(expected) = sha256 {
+        let actual = Sha256::digest(&bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            panic!(
+                "{}Expected: {}\nActual:   {}\n\n",
+                CHECKSUM_MISMATCH, expected, actual
+            );
+        }
+    }
+
+    extract_sdk(std::io::Cursor::new(bytes), sdkfolder)
+}
+
+#[cfg(not(feature = "archive"))]
+fn unzip_sdk(_: &Path, _: Option<String>, _: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
+    panic!("{}", MISSING_ARCHIVE_FEATURE)
+}
+
+#[cfg(feature = "archive")]
+fn extract_sdk(
+    archive: impl std::io::Read + std::io::Seek,
+    sdkfolder: PathBuf,
+) -> Result<PathBuf, Box<dyn Error>> {
+    use std::fs::{self, File};
+    use std::io;
+
+    use zip::read::ZipArchive;
+
+    let mut archive = ZipArchive::new(archive)?;
 
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)?;
@@ -42,12 +94,7 @@ fn dl_sdk(sdkfolder: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
     Ok(sdkfolder)
 }
 
-#[cfg(not(feature = "download"))]
-fn dl_sdk(_: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
-    Err(std::io::Error::new(std::io::ErrorKind::Other, "Download disabled").into())
-}
-
-#[cfg(feature = "download")]
+#[cfg(feature = "archive")]
 fn rn_sdk(libfolder: PathBuf) -> Result<(), Box<dyn Error>> {
     use std::fs;
 
@@ -97,41 +144,71 @@ fn main() {
         return;
     }
 
-    let sdk_path = dl_sdk(out_path.join("sdk/")).unwrap_or_else(|_| {
-        PathBuf::from(env::var("DISCORD_GAME_SDK_PATH").expect(MISSING_SDK_PATH))
-    });
+    println!("cargo:rerun-if-env-changed=DISCORD_GAME_SDK_BINDINGS");
 
-    println!("cargo:rerun-if-env-changed=DISCORD_GAME_SDK_PATH");
-    println!("cargo:rerun-if-changed={}", sdk_path.to_str().unwrap());
+    let prebuilt = env::var("DISCORD_GAME_SDK_BINDINGS").ok().or_else(|| {
+        if cfg!(feature = "prebuilt-bindings") {
+            Some(PREBUILT_BINDINGS[PREBUILT_BINDINGS.len() - 1].to_owned())
+        } else {
+            None
+        }
+    });
 
-    bindgen::builder()
-        .header(sdk_path.join("c/discord_game_sdk.h").to_str().unwrap())
-        .ctypes_prefix("ctypes")
-        .derive_copy(true)
-        .derive_debug(true)
-        .derive_default(true)
-        .derive_eq(true)
-        .derive_hash(true)
-        .derive_partialeq(true)
-        .generate_comments(false)
-        .impl_debug(true)
-        .impl_partialeq(true)
-        .parse_callbacks(Box::new(Callbacks))
-        .prepend_enum_name(false)
-        .whitelist_function("Discord.+")
-        .whitelist_type("[EI]?Discord.+")
-        .whitelist_var("DISCORD_.+")
-        .generate()
-        .expect("discord_game_sdk_sys: bindgen could not generate bindings")
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("discord_game_sdk_sys: could not write bindings to file");
-
-    std::fs::copy(out_path.join("bindings.rs"), "src/.generated.rs").unwrap();
+    let mut sdk_path = None;
+
+    if let Some(version) = prebuilt {
+        assert!(
+            PREBUILT_BINDINGS.contains(&version.as_str()),
+            "{}{}",
+            UNKNOWN_BINDINGS,
+            PREBUILT_BINDINGS.join(", ")
+        );
+
+        assert!(
+            env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap() == "64",
+            "{}",
+            INCOMPATIBLE_BINDINGS
+        );
+
+        let bindings = format!("bindings/{}.rs", version);
+        println!("cargo:rerun-if-changed={}", bindings);
+        std::fs::copy(bindings, out_path.join("bindings.rs")).unwrap();
+    } else {
+        let path = find_sdk(&out_path);
+
+        bindgen::builder()
+            .header(path.join("c/discord_game_sdk.h").to_str().unwrap())
+            .ctypes_prefix("ctypes")
+            .derive_copy(true)
+            .derive_debug(true)
+            .derive_default(true)
+            .derive_eq(true)
+            .derive_hash(true)
+            .derive_partialeq(true)
+            .generate_comments(false)
+            .impl_debug(true)
+            .impl_partialeq(true)
+            .parse_callbacks(Box::new(Callbacks))
+            .prepend_enum_name(false)
+            .whitelist_function("Discord.+")
+            .whitelist_type("[EI]?Discord.+")
+            .whitelist_var("DISCORD_.+")
+            .generate()
+            .expect("discord_game_sdk_sys: bindgen could not generate bindings")
+            .write_to_file(out_path.join("bindings.rs"))
+            .expect("discord_game_sdk_sys: could not write bindings to file");
+
+        std::fs::copy(out_path.join("bindings.rs"), "src/.generated.rs").unwrap();
+
+        sdk_path = Some(path);
+    }
 
     if cfg!(not(feature = "link")) {
         return;
     }
 
+    let sdk_path = sdk_path.unwrap_or_else(|| find_sdk(&out_path));
+
     match target.as_ref() {
         "x86_64-unknown-linux-gnu" => {
             assert!(
@@ -191,6 +268,32 @@ fn main() {
     }
 }
 
+fn find_sdk(out_path: &Path) -> PathBuf {
+    println!("cargo:rerun-if-env-changed=DISCORD_GAME_SDK_ZIP");
+    println!("cargo:rerun-if-env-changed=DISCORD_GAME_SDK_SHA256");
+
+    // A local archive takes precedence, and never falls back to the network
+    if let Ok(zip_path) = env::var("DISCORD_GAME_SDK_ZIP") {
+        println!("cargo:rerun-if-changed={}", zip_path);
+
+        return unzip_sdk(
+            Path::new(&zip_path),
+            env::var("DISCORD_GAME_SDK_SHA256").ok(),
+            out_path.join("sdk/"),
+        )
+        .unwrap_or_else(|error| panic!("{}Error: {}\n\n", INVALID_ARCHIVE, error));
+    }
+
+    let sdk_path = dl_sdk(out_path.join("sdk/")).unwrap_or_else(|_| {
+        PathBuf::from(env::var("DISCORD_GAME_SDK_PATH").expect(MISSING_SDK_PATH))
+    });
+
+    println!("cargo:rerun-if-env-changed=DISCORD_GAME_SDK_PATH");
+    println!("cargo:rerun-if-changed={}", sdk_path.to_str().unwrap());
+
+    sdk_path
+}
+
 #[derive(Debug)]
 struct Callbacks;
 
@@ -205,6 +308,9 @@ impl bindgen::callbacks::ParseCallbacks for Callbacks {
     }
 }
 
+// Versions of the SDK with bindings in `bindings/`, the last one is used by default
+const PREBUILT_BINDINGS: &[&str] = &["2.5.6"];
+
 const MISSING_SDK_PATH: &str = r#"
 
 discord_game_sdk_sys: Hello,
@@ -271,3 +377,68 @@ https://github.com/ldesgoui/discord_game_sdk
 Thanks, and apologies for the inconvenience
 
 "#;
+
+const UNKNOWN_BINDINGS: &str = r#"
+
+discord_game_sdk_sys: Hello,
+
+You are trying to use prebuilt bindings for the Discord Game SDK.
+Unfortunately, there are none for the version set in `DISCORD_GAME_SDK_BINDINGS`.
+
+Known versions: "#;
+
+const INCOMPATIBLE_BINDINGS: &str = r#"
+
+discord_game_sdk_sys: Hello,
+
+You are trying to use prebuilt bindings for the Discord Game SDK.
+Unfortunately, they were only generated for 64-bit targets.
+Unset `DISCORD_GAME_SDK_BINDINGS` and disable the `prebuilt-bindings` feature
+to generate bindings for this target with `bindgen`.
+
+Please report any issues you have at:
+https://github.com/ldesgoui/discord_game_sdk
+
+Thanks, and apologies for the inconvenience
+
+"#;
+
+const INVALID_ARCHIVE: &str = r#"
+
+discord_game_sdk_sys: Hello,
+
+You are trying to extract the Discord Game SDK from the archive set in `DISCORD_GAME_SDK_ZIP`.
+Unfortunately, it could not be read or extracted, make sure it is the SDK's `.zip` archive.
+
+Please report any issues you have at:
+https://github.com/ldesgoui/discord_game_sdk
+
+Thanks, and apologies for the inconvenience
+
+"#;
+
+#[cfg(feature = "archive")]
+const CHECKSUM_MISMATCH: &str = r#"
+
+discord_game_sdk_sys: Hello,
+
+You are trying to extract the Discord Game SDK from the archive set in `DISCORD_GAME_SDK_ZIP`.
+Its SHA-256 checksum does not match the one set in `DISCORD_GAME_SDK_SHA256`,
+make sure it is the archive you expect, and update the checksum if the SDK was updated.
+
+"#;
+
+#[cfg(not(feature = "archive"))]
+const MISSING_ARCHIVE_FEATURE: &str = r#"
+
+discord_game_sdk_sys: Hello,
+
+You are trying to extract the Discord Game SDK from the archive set in `DISCORD_GAME_SDK_ZIP`.
+This requires the `archive` feature, which is also enabled by the `download` feature.
+
+Please report any issues you have at:
+https://github.com/ldesgoui/discord_game_sdk
+
+Thanks, and apologies for the inconvenience
+
+"#;
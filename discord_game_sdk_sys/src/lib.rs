@@ -51,6 +51,38 @@
 //! This allows for `cargo run` to function.
 //!
 //!
+//! ### `archive`
+//!
+//! Extracts the SDK from a local `.zip` archive instead of `DISCORD_GAME_SDK_PATH`,
+//! renaming the libraries as described above, so that builds are reproducible
+//! and never reach the network:
+//!
+//! ```sh
+//! export DISCORD_GAME_SDK_ZIP=/path/to/discord_game_sdk.zip
+//! # Optional, the build fails if the archive doesn't match
+//! export DISCORD_GAME_SDK_SHA256=0123456789abcdef...
+//! ```
+//!
+//! The SDK is extracted to the build's `OUT_DIR`, `DISCORD_GAME_SDK_PATH` is then ignored.
+//!
+//!
+//! ### `prebuilt-bindings`
+//!
+//! Uses bindings checked into this crate instead of generating them with `bindgen`,
+//! which removes the need for libclang and the SDK headers.
+//! Without the `link` feature, the SDK doesn't need to be downloaded at all.
+//!
+//! Bindings are available for 64-bit targets and SDK version `2.5.6`, which is used by default.
+//! The version can be selected by setting `DISCORD_GAME_SDK_BINDINGS`, which also enables
+//! this mode without the feature:
+//!
+//! ```sh
+//! export DISCORD_GAME_SDK_BINDINGS=2.5.6
+//! ```
+//!
+//! The sizes and alignments of the SDK's structures are asserted at compile time.
+//!
+//!
 //! # Legal
 //!
 //! You *MUST* acquaint yourself with and agree to the [official terms of the Discord Game SDK].